@@ -0,0 +1,197 @@
+//! Generates the solution registry of the `all` binary: every solution in `src/bin` is compiled
+//! as a module of that binary so that `cargo all` and `cargo time` can run them in-process.
+//! Also generates a test for every example listed in the example manifests of the solutions.
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use tinyjson::JsonValue;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut stems: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension()? != "rs" {
+                        return None;
+                    }
+                    let stem = path.file_stem()?.to_str()?.to_string();
+                    is_solution_stem(&stem).then_some(stem)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // year-prefixed solutions come first so that they win over legacy ones of the same puzzle.
+    stems.sort_unstable_by_key(|stem| (stem.len() == 2, stem.clone()));
+
+    let modules: String = stems
+        .iter()
+        .map(|stem| {
+            let path = bin_dir.join(format!("{stem}.rs"));
+            format!(
+                "    #[allow(dead_code)]\n    #[path = {:?}]\n    mod solution_{stem};\n",
+                path.display().to_string()
+            )
+        })
+        .collect();
+
+    let solutions: String = stems
+        .iter()
+        .map(|stem| format!("            solution_{stem}::SOLUTION,\n"))
+        .collect();
+
+    let registry = format!(
+        "#[cfg(not(any(test, feature = \"dhat-heap\", feature = \"count-alloc\")))]
+mod days {{
+    use advent_of_code::template::registry::Solution;
+
+{modules}
+    pub fn solutions() -> Vec<Solution> {{
+        vec![
+{solutions}        ]
+    }}
+}}
+
+// solutions define their own global allocator with `dhat-heap` or `count-alloc`
+// and are tested in their own binary.
+#[cfg(any(test, feature = \"dhat-heap\", feature = \"count-alloc\"))]
+mod days {{
+    pub fn solutions() -> Vec<advent_of_code::template::registry::Solution> {{
+        vec![]
+    }}
+}}
+"
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+
+    write_example_tests(Path::new(&manifest_dir), &stems, Path::new(&out_dir));
+}
+
+/// Writes the tests of each solution to `examples/<stem>.rs`, which is included by
+/// `example_tests!`. Every solution gets a file, even if it has no examples.
+fn write_example_tests(manifest_dir: &Path, stems: &[String], out_dir: &Path) {
+    println!("cargo:rerun-if-env-changed=AOC_YEAR");
+
+    let data_dir = manifest_dir.join("data");
+    let current_year = env::var("AOC_YEAR").ok();
+    let tests_dir = out_dir.join("examples");
+    fs::create_dir_all(&tests_dir).unwrap();
+
+    let legacy_dir = data_dir.join("examples");
+    if legacy_dir.exists() {
+        println!("cargo:rerun-if-changed={}", legacy_dir.display());
+    }
+
+    for stem in stems {
+        let (year, day) = match stem.split_once('_') {
+            Some((year, day)) => (Some(year.to_string()), day),
+            None => (current_year.clone(), stem.as_str()),
+        };
+
+        let examples_dir = year
+            .as_ref()
+            .map(|year| data_dir.join(year).join("examples"));
+        if let Some(dir) = examples_dir.as_ref().filter(|dir| dir.exists()) {
+            println!("cargo:rerun-if-changed={}", dir.display());
+        }
+
+        // same lookup as `resolve_data_path`: the legacy layout is used for the configured year.
+        let file_name = format!("{day}.json");
+        let path = examples_dir
+            .map(|dir| dir.join(&file_name))
+            .filter(|path| path.exists())
+            .or_else(|| {
+                Some(legacy_dir.join(&file_name))
+                    .filter(|path| year == current_year && path.exists())
+            });
+
+        let tests: String = path
+            .map(|path| example_names(&path))
+            .unwrap_or_default()
+            .iter()
+            .map(|name| {
+                format!(
+                    "#[test]
+fn {}() {{
+    advent_of_code::template::examples::run_example(&super::SOLUTION, {name:?});
+}}
+",
+                    to_ident(name)
+                )
+            })
+            .collect();
+
+        fs::write(tests_dir.join(format!("{stem}.rs")), tests).unwrap();
+    }
+}
+
+/// Reads the names of the examples in a manifest, warning about manifests that can not be read.
+fn example_names(path: &PathBuf) -> Vec<String> {
+    let names = fs::read_to_string(path)
+        .ok()
+        .and_then(|manifest| manifest.parse::<JsonValue>().ok())
+        .and_then(|json| {
+            json.get::<HashMap<String, JsonValue>>()?
+                .get("examples")?
+                .get::<Vec<JsonValue>>()?
+                .iter()
+                .map(|example| {
+                    example
+                        .get::<HashMap<String, JsonValue>>()?
+                        .get("name")?
+                        .get::<String>()
+                        .cloned()
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+
+    names.unwrap_or_else(|| {
+        println!(
+            "cargo:warning=Could not read the examples in {}",
+            path.display()
+        );
+        vec![]
+    })
+}
+
+/// Turns the name of an example into a test name, e.g. `larger grid` into `larger_grid`.
+fn to_ident(name: &str) -> String {
+    let ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if ident.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        ident
+    } else {
+        format!("example_{ident}")
+    }
+}
+
+/// Matches `DD` and `YYYY_DD`.
+fn is_solution_stem(stem: &str) -> bool {
+    let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+    match stem.split_once('_') {
+        Some((year, day)) => year.len() == 4 && is_number(year) && day.len() == 2 && is_number(day),
+        None => stem.len() == 2 && is_number(stem),
+    }
+}
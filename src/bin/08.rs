@@ -1,6 +1,12 @@
 use std::{cmp::Reverse, collections::BinaryHeap, str::FromStr};
 
-advent_of_code::solution!(8);
+use advent_of_code::template::Context;
+
+advent_of_code::solution!(
+    8,
+    params = { connections: 1000 },
+    example_params = { connections: 10 }
+);
 
 #[derive(Debug)]
 struct Coord {
@@ -32,16 +38,16 @@ impl Coord {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &str, context: &Context) -> Option<u64> {
     let coords: Vec<_> = input
         .lines()
         .map(|line| Coord::from_str(line).expect("coord"))
         .collect();
 
-    Some(best_connections(coords, 3, 1000))
+    Some(best_connections(coords, 3, context.param("connections")))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str, _context: &Context) -> Option<u64> {
     let coords: Vec<_> = input
         .lines()
         .map(|line| Coord::from_str(line).expect("coord"))
@@ -168,18 +174,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let coords = advent_of_code::template::read_file("examples", DAY)
-            .lines()
-            .map(|line| Coord::from_str(line).expect("coord"))
-            .collect();
-
-        let result = best_connections(coords, 3, 10);
-        assert_eq!(result, 40);
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", DAY),
+            &Context::example(&PARAMS),
+        );
+        assert_eq!(result, Some(40));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", DAY),
+            &Context::example(&PARAMS),
+        );
         assert_eq!(result, Some(25272));
     }
 }
@@ -0,0 +1,10 @@
+//! Runs `all` and `time` with every solution compiled in, so no `cargo run` is spawned per day.
+//! The modules of the solutions are generated by `build.rs`.
+use advent_of_code::template::{cli, registry};
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+fn main() {
+    registry::install(days::solutions());
+    cli::run();
+}
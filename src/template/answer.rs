@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use crate::template::ocr;
+
+/// A value returned by a solution part, controlling how it is printed, submitted and compared
+/// against its accepted answer.
+pub trait Answer {
+    /// The answer as it is submitted and compared against the accepted answer, e.g. `42`.
+    fn submission(&self) -> String;
+
+    /// The answer as it is printed, the submission by default.
+    fn display(&self) -> String {
+        self.submission()
+    }
+
+    /// Why the answer is most likely wrong, answers with a warning are not submitted.
+    fn warning(&self) -> Option<String> {
+        None
+    }
+}
+
+macro_rules! unsigned_answer {
+    ($($t:ty)*) => {$(
+        impl Answer for $t {
+            fn submission(&self) -> String {
+                self.to_string()
+            }
+        }
+    )*};
+}
+
+// signed answers warn about negative values, which are never accepted.
+macro_rules! signed_answer {
+    ($($t:ty)*) => {$(
+        impl Answer for $t {
+            fn submission(&self) -> String {
+                self.to_string()
+            }
+
+            fn warning(&self) -> Option<String> {
+                (*self < 0).then(|| format!("{self} is negative, puzzle answers never are."))
+            }
+        }
+    )*};
+}
+
+unsigned_answer!(u8 u16 u32 u64 u128 usize);
+signed_answer!(i8 i16 i32 i64 i128 isize);
+
+/// The type of a part's answer, used by `solution!` to warn at compile time about parts that
+/// return a signed integer: `AnswerType::of(&answer).check()` is deprecated for those.
+/// A day that returns signed answers on purpose opts out with `solution!(9, signed)`.
+#[doc(hidden)]
+pub struct AnswerType<T>(PhantomData<T>);
+
+impl<T> AnswerType<T> {
+    pub fn of(_answer: &Option<T>) -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// The check of every other answer type, which the inherent `check` of signed types shadows.
+#[doc(hidden)]
+pub trait UncheckedAnswerType {
+    fn check(&self) {}
+}
+
+impl<T> UncheckedAnswerType for AnswerType<T> {}
+
+macro_rules! signed_answer_type {
+    ($($t:ty)*) => {$(
+        impl AnswerType<$t> {
+            #[deprecated(
+                note = "this part returns a signed integer, but negative answers are never accepted. \
+                        Return an unsigned integer, or use `solution!(DAY, signed)` if the sign is needed"
+            )]
+            pub fn check(&self) {}
+        }
+    )*};
+}
+
+signed_answer_type!(i8 i16 i32 i64 i128 isize);
+
+impl Answer for String {
+    fn submission(&self) -> String {
+        self.trim().to_string()
+    }
+
+    fn display(&self) -> String {
+        self.clone()
+    }
+}
+
+impl Answer for &str {
+    fn submission(&self) -> String {
+        self.trim().to_string()
+    }
+
+    fn display(&self) -> String {
+        (*self).to_string()
+    }
+}
+
+/// Letters drawn on a grid, e.g. by lighting up pixels, with `#` for lit and `.` for dark cells.
+/// The grid is printed as is and submitted as the letters read by [`ocr::recognize`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LetterGrid(pub String);
+
+impl LetterGrid {
+    /// Draws a grid from its rows of cells, `true` for lit cells.
+    pub fn from_cells<R: AsRef<[bool]>>(rows: &[R]) -> Self {
+        let rows: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                row.as_ref()
+                    .iter()
+                    .map(|&lit| if lit { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Self(rows.join("\n"))
+    }
+}
+
+impl Answer for LetterGrid {
+    /// The letters on the grid, or the grid itself if they can not be read.
+    fn submission(&self) -> String {
+        ocr::recognize(&self.0).unwrap_or_else(|| self.0.trim().to_string())
+    }
+
+    fn display(&self) -> String {
+        self.0.clone()
+    }
+
+    fn warning(&self) -> Option<String> {
+        ocr::recognize(&self.0)
+            .is_none()
+            .then(|| "the letters of the grid can not be read, submit them by hand.".into())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, LetterGrid};
+
+    #[test]
+    fn submits_numbers() {
+        assert_eq!(42_u64.submission(), "42");
+        assert_eq!(42_u64.warning(), None);
+        assert_eq!(7_i64.warning(), None);
+        assert_eq!((-7_i64).submission(), "-7");
+        assert_eq!(
+            (-7_i64).warning().unwrap(),
+            "-7 is negative, puzzle answers never are."
+        );
+    }
+
+    #[test]
+    fn submits_strings() {
+        assert_eq!(" abc\n".submission(), "abc");
+        assert_eq!(" abc\n".display(), " abc\n");
+        assert_eq!(String::from("abc").warning(), None);
+    }
+
+    #[test]
+    fn draws_letter_grids() {
+        let grid = LetterGrid::from_cells(&[[true, false], [false, true]]);
+        assert_eq!(grid.display(), "#.\n.#");
+        assert_eq!(grid.submission(), "#.\n.#");
+        assert!(grid.warning().is_some());
+    }
+
+    #[test]
+    fn reads_letter_grids() {
+        let grid = LetterGrid(
+            [
+                ".##..###.",
+                "#..#.#..#",
+                "#..#.###.",
+                "####.#..#",
+                "#..#.#..#",
+                "#..#.###.",
+            ]
+            .join("\n"),
+        );
+        assert_eq!(grid.submission(), "AB");
+        assert_eq!(grid.warning(), None);
+        assert!(grid.display().starts_with(".##..###."));
+    }
+}
@@ -0,0 +1,660 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// The outcome of submitting an answer, as reported by adventofcode.com.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Option<Duration> },
+}
+
+impl Verdict {
+    /// Parses the message of an answer submission response.
+    pub fn from_response(message: &str) -> Option<Self> {
+        if message.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if message.contains("too high") {
+            Some(Verdict::TooHigh)
+        } else if message.contains("too low") {
+            Some(Verdict::TooLow)
+        } else if message.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if message.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited {
+                wait: parse_wait(message),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited { .. } => "rate_limited",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate-limited, wait {}s", wait.as_secs())
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate-limited"),
+        }
+    }
+}
+
+/// Parses durations like `You have 1m 23s left to wait.`
+fn parse_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Why an answer was not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    KnownWrong(Verdict),
+    NotBelow(String),
+    NotAbove(String),
+    RateLimited(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => {
+                write!(f, "this part was already solved with \"{answer}\".")
+            }
+            Refusal::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted: {verdict}.")
+            }
+            Refusal::NotBelow(bound) => {
+                write!(f, "the answer must be lower than \"{bound}\" (too high).")
+            }
+            Refusal::NotAbove(bound) => {
+                write!(f, "the answer must be higher than \"{bound}\" (too low).")
+            }
+            Refusal::RateLimited(wait) => {
+                write!(
+                    f,
+                    "rate-limited, wait {}s before submitting.",
+                    wait.as_secs()
+                )
+            }
+        }
+    }
+}
+
+/// Outcome of comparing a part's result against its accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerCheck {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// The part has no accepted answer yet.
+    Unknown,
+}
+
+impl AnswerCheck {
+    pub fn is_fail(&self) -> bool {
+        matches!(self, AnswerCheck::Fail { .. })
+    }
+}
+
+impl Display for AnswerCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerCheck::Pass => write!(f, "✔ pass"),
+            AnswerCheck::Fail { expected } => write!(f, "✘ fail (expected {expected})"),
+            AnswerCheck::Unknown => write!(f, "-"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single submitted answer.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Ledger of every submitted answer.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Submission>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns an empty ledger.
+    /// A ledger that can not be read is an error, it must not be overwritten by a new one.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(content) => Answers::try_from(content)
+                .map_err(|e| format!("\"{ANSWERS_FILE_PATH}\" can not be read: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("\"{ANSWERS_FILE_PATH}\" can not be read: {e}")),
+        }
+    }
+
+    /// Records the verdict of a submitted answer.
+    pub fn record(&mut self, puzzle: PuzzleId, part: u8, answer: &str, verdict: Verdict) {
+        self.data.push(Submission {
+            puzzle,
+            part,
+            answer: answer.trim().to_string(),
+            verdict,
+            timestamp: now(),
+        });
+    }
+
+    /// Returns the accepted answer of a part, if any.
+    pub fn correct_answer(&self, puzzle: PuzzleId, part: u8) -> Option<&str> {
+        self.submissions(puzzle, part)
+            .find(|s| s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Compares a part's result against its accepted answer. A missing result fails
+    /// as soon as the part has been accepted.
+    pub fn verify(&self, puzzle: PuzzleId, part: u8, result: Option<&str>) -> AnswerCheck {
+        match self.correct_answer(puzzle, part) {
+            None => AnswerCheck::Unknown,
+            Some(expected) if result.map(str::trim) == Some(expected) => AnswerCheck::Pass,
+            Some(expected) => AnswerCheck::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    /// Checks whether an answer is worth submitting given the verdicts recorded so far.
+    pub fn check(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Refusal> {
+        self.check_at(puzzle, part, answer, now())
+    }
+
+    fn check_at(&self, puzzle: PuzzleId, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let answer = answer.trim();
+
+        if let Some(correct) = self.correct_answer(puzzle, part) {
+            return Err(Refusal::AlreadyCorrect(correct.to_string()));
+        }
+
+        if let Some(s) = self
+            .submissions(puzzle, part)
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(Refusal::KnownWrong(s.verdict.clone()));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |verdict: Verdict| {
+                self.submissions(puzzle, part)
+                    .filter(move |s| s.verdict == verdict)
+                    .filter_map(|s| s.answer.parse::<i128>().ok())
+            };
+
+            if let Some(high) = bound(Verdict::TooHigh).min()
+                && value >= high
+            {
+                return Err(Refusal::NotBelow(high.to_string()));
+            }
+
+            if let Some(low) = bound(Verdict::TooLow).max()
+                && value <= low
+            {
+                return Err(Refusal::NotAbove(low.to_string()));
+            }
+        }
+
+        // NOTE: the cooldown applies to the whole puzzle, not only to the submitted part.
+        let cooldown = self
+            .data
+            .iter()
+            .filter(|s| s.puzzle == puzzle)
+            .filter_map(|s| match s.verdict {
+                Verdict::RateLimited { wait: Some(wait) } => Some(s.timestamp + wait.as_secs()),
+                _ => None,
+            })
+            .max();
+
+        if let Some(until) = cooldown
+            && until > now
+        {
+            return Err(Refusal::RateLimited(Duration::from_secs(until - now)));
+        }
+
+        Ok(())
+    }
+
+    fn submissions(&self, puzzle: PuzzleId, part: u8) -> impl Iterator<Item = &Submission> {
+        self.data
+            .iter()
+            .filter(move |s| s.puzzle == puzzle && s.part == part)
+    }
+}
+
+/// Seconds since the unix epoch.
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        if let Verdict::RateLimited { wait: Some(wait) } = value.verdict {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert(
+                "wait_seconds".into(),
+                JsonValue::Number(wait.as_secs() as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected submission.year to be a Year struct.")?;
+
+        let puzzle = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| day.parse().ok())
+            .and_then(Day::new)
+            // recorded answers stay valid if the calendar of their year is configured differently.
+            .map(|day| PuzzleId::new(year, day))
+            .ok_or("Expected submission.day to be a Day.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|&part| part as u8)
+            .filter(|part| *part == 1 || *part == 2)
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let wait = json
+            .get("wait_seconds")
+            .and_then(|v| v.get::<f64>())
+            .map(|&secs| Duration::from_secs(secs as u64));
+
+        let verdict = match json.get("verdict").and_then(|v| v.get::<String>()) {
+            Some(v) if v == "correct" => Verdict::Correct,
+            Some(v) if v == "too_high" => Verdict::TooHigh,
+            Some(v) if v == "too_low" => Verdict::TooLow,
+            Some(v) if v == "wrong" => Verdict::Wrong,
+            Some(v) if v == "rate_limited" => Verdict::RateLimited { wait },
+            _ => return Err("Expected submission.verdict to be a known verdict.".into()),
+        };
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|&t| t as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        Ok(Submission {
+            puzzle,
+            part,
+            answer: answer.clone(),
+            verdict,
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Answers, Refusal, Submission, Verdict};
+    use crate::puzzle;
+
+    fn submission(answer: &str, verdict: Verdict, timestamp: u64) -> Submission {
+        Submission {
+            puzzle: puzzle!(2025, 1),
+            part: 1,
+            answer: answer.into(),
+            verdict,
+            timestamp,
+        }
+    }
+
+    mod verdicts {
+        use super::{Duration, Verdict};
+
+        #[test]
+        fn parses_responses() {
+            assert_eq!(
+                Verdict::from_response("That's the right answer! You are one gold star closer."),
+                Some(Verdict::Correct)
+            );
+            assert_eq!(
+                Verdict::from_response("That's not the right answer; your answer is too high."),
+                Some(Verdict::TooHigh)
+            );
+            assert_eq!(
+                Verdict::from_response("That's not the right answer; your answer is too low."),
+                Some(Verdict::TooLow)
+            );
+            assert_eq!(
+                Verdict::from_response("That's not the right answer. If you're stuck..."),
+                Some(Verdict::Wrong)
+            );
+            assert_eq!(Verdict::from_response("Something else."), None);
+        }
+
+        #[test]
+        fn parses_wait_times() {
+            assert_eq!(
+                Verdict::from_response(
+                    "You gave an answer too recently. You have 1m 23s left to wait."
+                ),
+                Some(Verdict::RateLimited {
+                    wait: Some(Duration::from_secs(83))
+                })
+            );
+            assert_eq!(
+                Verdict::from_response("You gave an answer too recently."),
+                Some(Verdict::RateLimited { wait: None })
+            );
+        }
+    }
+
+    mod check {
+        use super::{Answers, Duration, Refusal, Verdict, submission};
+        use crate::puzzle;
+
+        #[test]
+        fn allows_unknown_answers() {
+            let answers = Answers::default();
+            assert_eq!(answers.check_at(puzzle!(2025, 1), 1, "42", 0), Ok(()));
+        }
+
+        #[test]
+        fn refuses_solved_parts() {
+            let answers = Answers {
+                data: vec![submission("42", Verdict::Correct, 0)],
+            };
+            assert_eq!(
+                answers.check_at(puzzle!(2025, 1), 1, "43", 0),
+                Err(Refusal::AlreadyCorrect("42".into()))
+            );
+            assert_eq!(answers.check_at(puzzle!(2025, 1), 2, "43", 0), Ok(()));
+        }
+
+        #[test]
+        fn refuses_known_wrong_answers() {
+            let answers = Answers {
+                data: vec![submission("foo", Verdict::Wrong, 0)],
+            };
+            assert_eq!(
+                answers.check_at(puzzle!(2025, 1), 1, "foo\n", 0),
+                Err(Refusal::KnownWrong(Verdict::Wrong))
+            );
+        }
+
+        #[test]
+        fn refuses_answers_outside_bounds() {
+            let answers = Answers {
+                data: vec![
+                    submission("100", Verdict::TooHigh, 0),
+                    submission("80", Verdict::TooHigh, 0),
+                    submission("10", Verdict::TooLow, 0),
+                ],
+            };
+            assert_eq!(
+                answers.check_at(puzzle!(2025, 1), 1, "90", 0),
+                Err(Refusal::NotBelow("80".into()))
+            );
+            assert_eq!(
+                answers.check_at(puzzle!(2025, 1), 1, "5", 0),
+                Err(Refusal::NotAbove("10".into()))
+            );
+            assert_eq!(answers.check_at(puzzle!(2025, 1), 1, "50", 0), Ok(()));
+        }
+
+        #[test]
+        fn refuses_while_rate_limited() {
+            let answers = Answers {
+                data: vec![submission(
+                    "50",
+                    Verdict::RateLimited {
+                        wait: Some(Duration::from_secs(60)),
+                    },
+                    1000,
+                )],
+            };
+            assert_eq!(
+                answers.check_at(puzzle!(2025, 1), 1, "50", 1030),
+                Err(Refusal::RateLimited(Duration::from_secs(30)))
+            );
+            assert_eq!(answers.check_at(puzzle!(2025, 1), 1, "50", 1060), Ok(()));
+        }
+    }
+
+    mod verify {
+        use super::{Answers, Verdict, submission};
+        use crate::{puzzle, template::answers::AnswerCheck};
+
+        #[test]
+        fn compares_against_accepted_answers() {
+            let answers = Answers {
+                data: vec![
+                    submission("41", Verdict::TooLow, 0),
+                    submission("42", Verdict::Correct, 0),
+                ],
+            };
+
+            assert_eq!(
+                answers.verify(puzzle!(2025, 1), 1, Some("42")),
+                AnswerCheck::Pass
+            );
+            assert_eq!(
+                answers.verify(puzzle!(2025, 1), 1, Some("41")),
+                AnswerCheck::Fail {
+                    expected: "42".into()
+                }
+            );
+            assert_eq!(
+                answers.verify(puzzle!(2025, 1), 1, None),
+                AnswerCheck::Fail {
+                    expected: "42".into()
+                }
+            );
+            assert_eq!(
+                answers.verify(puzzle!(2025, 1), 2, Some("42")),
+                AnswerCheck::Unknown
+            );
+        }
+    }
+
+    mod serialization {
+        use super::{Answers, Duration, Verdict, submission};
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn round_trips_answers() {
+            let answers = Answers {
+                data: vec![
+                    submission("100", Verdict::TooHigh, 10),
+                    submission(
+                        "50",
+                        Verdict::RateLimited {
+                            wait: Some(Duration::from_secs(60)),
+                        },
+                        20,
+                    ),
+                ],
+            };
+
+            let json = JsonValue::from(answers).stringify().unwrap();
+            let parsed = Answers::try_from(json).unwrap();
+
+            assert_eq!(parsed.data.len(), 2);
+            assert_eq!(parsed.data[0].answer, "100");
+            assert_eq!(parsed.data[0].verdict, Verdict::TooHigh);
+            assert_eq!(parsed.data[0].timestamp, 10);
+            assert_eq!(
+                parsed.data[1].verdict,
+                Verdict::RateLimited {
+                    wait: Some(Duration::from_secs(60))
+                }
+            );
+        }
+
+        #[test]
+        fn keeps_days_outside_the_configured_calendar() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "20", "part": 1, "answer": "1", "verdict": "correct", "timestamp": 0 }] }"#.to_string();
+            let parsed = Answers::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].puzzle.day, 20);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unknown_verdicts() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part": 1, "answer": "1", "verdict": "maybe", "timestamp": 0 }] }"#.to_string();
+            Answers::try_from(json).unwrap();
+        }
+    }
+}
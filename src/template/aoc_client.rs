@@ -0,0 +1,254 @@
+/// Built-in client for adventofcode.com, used when aoc-cli is not installed.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::PuzzleId;
+use crate::template::aoc_cli::{create_parent_dir, get_input_path, get_puzzle_path};
+use crate::template::description::html_to_markdown;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+const BASE_URL_ENV: &str = "AOC_BASE_URL";
+const SESSION_FILE: &str = ".adventofcode.session";
+const USER_AGENT: &str = "github.com/enricava/aoc25 by advent_of_code template";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    BadStatus(u16, String),
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or write it to ~/{SESSION_FILE}."
+            ),
+            AocClientError::BadStatus(status, url) => {
+                write!(f, "adventofcode.com responded with {status} for {url}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                AocClientError::BadStatus(status, response.get_url().to_string())
+            }
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// Creates a client from `ADVENT_OF_CODE_SESSION` (or `~/.adventofcode.session`)
+    /// and `AOC_BASE_URL`, which defaults to adventofcode.com.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn fetch_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.puzzle_url(puzzle)))
+    }
+
+    /// Fetches the puzzle page as HTML.
+    pub fn fetch_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&self.puzzle_url(puzzle))
+    }
+
+    /// Submits an answer and returns the message of the response page.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.puzzle_url(puzzle));
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        Ok(html_to_markdown(&html))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()?)
+    }
+
+    fn puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+}
+
+fn read_session() -> Option<String> {
+    let from_env = env::var(SESSION_ENV).ok();
+    let from_file = || {
+        let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+        fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()
+    };
+
+    from_env
+        .or_else(from_file)
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = html_to_markdown(&client.fetch_puzzle(puzzle)?);
+
+    let puzzle_path = get_puzzle_path(puzzle);
+    create_parent_dir(&puzzle_path);
+    fs::write(&puzzle_path, &description)?;
+
+    println!("{description}");
+    Ok(())
+}
+
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let input = client.fetch_input(puzzle)?;
+    let description = html_to_markdown(&client.fetch_puzzle(puzzle)?);
+
+    create_parent_dir(&input_path);
+    create_parent_dir(&puzzle_path);
+    fs::write(&input_path, input)?;
+    fs::write(&puzzle_path, description)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Submits an answer and returns the response message.
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<String, AocClientError> {
+    AocClient::from_env()?.submit(puzzle, part, result)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::AocClient;
+    use crate::puzzle;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves a single request with the given body and returns the raw request.
+    fn mock_server(body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut payload = vec![0; content_length];
+            reader.read_exact(&mut payload).unwrap();
+            request.push_str(&String::from_utf8(payload).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (url, server) = mock_server("1\n2\n3\n");
+        let client = AocClient::new(&url, "abc");
+
+        let input = client.fetch_input(puzzle!(2024, 5)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "1\n2\n3\n");
+        assert!(request.starts_with("GET /2024/day/5/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) =
+            mock_server("<main><article><p>That's the right answer!</p></article></main>");
+        let client = AocClient::new(&url, "abc");
+
+        let message = client.submit(puzzle!(2025, 1), 2, "42").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(message, "That's the right answer!\n");
+        assert!(request.starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+}
@@ -0,0 +1,251 @@
+/// Statistics of benchmarked solution parts.
+use std::{collections::HashMap, env, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Minimum number of samples taken for a benchmark, regardless of its time budget but not of a timeout.
+pub const MIN_SAMPLES: u128 = 10;
+
+/// Controls how long a part is benchmarked.
+///
+/// Every value can be overridden with an environment variable:
+/// `AOC_BENCH_BUDGET_MS`, `AOC_BENCH_MAX_SAMPLES` and `AOC_BENCH_WARMUP`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent sampling, as long as [`MIN_SAMPLES`] samples were taken.
+    pub budget: Duration,
+    /// Upper bound of samples, regardless of the time budget.
+    pub max_samples: u128,
+    /// Iterations run before sampling, stopped early once they exceed a tenth of the budget.
+    pub warmup: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            max_samples: 10_000,
+            warmup: 3,
+        }
+    }
+}
+
+impl BenchConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let var = |name: &str| env::var(name).ok().and_then(|x| x.parse::<u64>().ok());
+
+        Self {
+            budget: var("AOC_BENCH_BUDGET_MS").map_or(default.budget, Duration::from_millis),
+            max_samples: var("AOC_BENCH_MAX_SAMPLES")
+                .map_or(default.max_samples, u128::from)
+                .max(MIN_SAMPLES),
+            warmup: var("AOC_BENCH_WARMUP").map_or(default.warmup, u128::from),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Summary of the samples taken for a part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    /// Number of samples, `0` for timings stored before statistics were kept.
+    pub samples: u128,
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    /// Samples outside of 1.5 times the interquartile range.
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes the statistics of a non-empty set of samples.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "expecting at least one sample");
+
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = if sorted.len() > 1 {
+            sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = sorted
+            .iter()
+            .filter(|&&x| x < q1 - fence || x > q3 + fence)
+            .count();
+
+        Self {
+            samples: sorted.len() as u128,
+            mean: from_nanos(mean),
+            min: from_nanos(sorted[0]),
+            median: from_nanos(percentile(&sorted, 0.5)),
+            p95: from_nanos(percentile(&sorted, 0.95)),
+            max: from_nanos(sorted[sorted.len() - 1]),
+            stddev: from_nanos(variance.sqrt()),
+            outliers: outliers as u128,
+        }
+    }
+
+    /// Statistics of a timing that only kept a single duration.
+    pub fn from_duration(duration: Duration, samples: u128) -> Self {
+        Self {
+            samples,
+            mean: duration,
+            min: duration,
+            median: duration,
+            p95: duration,
+            max: duration,
+            stddev: Duration::ZERO,
+            outliers: 0,
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let pos = p * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// Parses a duration formatted with `{:?}`, e.g. `74.13ns`, `1.2µs`, `3ms` or `2s`.
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse = |postfix: &str| s.trim().strip_suffix(postfix)?.parse::<f64>().ok();
+
+    let nanos = parse("ns")
+        .or_else(|| parse("µs").map(|x| x * 1_000_f64))
+        .or_else(|| parse("ms").map(|x| x * 1_000_000_f64))
+        .or_else(|| parse("s").map(|x| x * 1_000_000_000_f64))?;
+
+    Some(from_nanos(nanos))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean_nanos".into(), nanos(value.mean));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("max_nanos".into(), nanos(value.max));
+        map.insert("stddev_nanos".into(), nanos(value.stddev));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: from_nanos(number("mean_nanos")?),
+            min: from_nanos(number("min_nanos")?),
+            median: from_nanos(number("median_nanos")?),
+            p95: from_nanos(number("p95_nanos")?),
+            max: from_nanos(number("max_nanos")?),
+            stddev: from_nanos(number("stddev_nanos")?),
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{BenchStats, parse_duration};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 12, 11, 13, 14, 10, 12, 11, 13, 100]));
+
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.median, Duration::from_nanos(12));
+        assert_eq!(stats.mean, Duration::from_nanos(21));
+        assert_eq!(stats.p95, Duration::from_nanos(61));
+        assert_eq!(stats.stddev, Duration::from_nanos(28));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn handles_single_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[42]));
+
+        assert_eq!(
+            stats,
+            BenchStats::from_duration(Duration::from_nanos(42), 1)
+        );
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("74.13ns"), Some(Duration::from_nanos(74)));
+        assert_eq!(parse_duration("1.5µs"), Some(Duration::from_nanos(1500)));
+        assert_eq!(parse_duration("10ms"), Some(Duration::from_millis(10)));
+        assert_eq!(parse_duration("2s"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_duration("fast"), None);
+    }
+
+    #[test]
+    fn round_trips_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 12, 11, 13, 14]));
+        let json = JsonValue::from(&stats);
+
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+}
@@ -0,0 +1,282 @@
+/// Command-line interface shared by the main binary and the `all` binary.
+use crate::template::commands::{
+    all, download, extract, migrate, read, scaffold, solve, status, time, watch,
+};
+use args::{AppArguments, parse};
+
+#[cfg(feature = "today")]
+use crate::template::PuzzleId;
+#[cfg(feature = "today")]
+use std::process;
+
+mod args {
+    use crate::template::commands::scaffold::DEFAULT_TEMPLATE;
+    use crate::template::runner::{parse_timeout, timeout_from_env};
+    use crate::template::{OutputFormat, PuzzleId, Year};
+    use std::{process, time::Duration};
+
+    pub enum AppArguments {
+        Download {
+            puzzle: PuzzleId,
+        },
+        Read {
+            puzzle: PuzzleId,
+        },
+        Extract {
+            puzzle: PuzzleId,
+            block: Option<usize>,
+            overwrite: bool,
+        },
+        Scaffold {
+            puzzle: PuzzleId,
+            download: bool,
+            overwrite: bool,
+            overwrite_data: bool,
+            template: String,
+        },
+        Solve {
+            puzzle: PuzzleId,
+            release: bool,
+            dhat: bool,
+            submit: Option<u8>,
+            format: OutputFormat,
+            timeout: Option<Duration>,
+            watch: bool,
+            force: bool,
+        },
+        All {
+            year: Year,
+            release: bool,
+            format: OutputFormat,
+            timeout: Option<Duration>,
+        },
+        Time {
+            all: bool,
+            puzzle: Option<PuzzleId>,
+            year: Year,
+            store: bool,
+            format: OutputFormat,
+            compare: bool,
+            threshold: Option<f64>,
+            memory: bool,
+            timeout: Option<Duration>,
+        },
+        Migrate {
+            year: Year,
+        },
+        Status {
+            year: Year,
+            tests: bool,
+            readme: bool,
+        },
+        #[cfg(feature = "today")]
+        Today,
+    }
+
+    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let app_args = match args.subcommand()?.as_deref() {
+            Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
+                release: args.contains("--release"),
+                format: parse_format(&mut args)?,
+                timeout: parse_timeout_arg(&mut args)?,
+            },
+            Some("time") => {
+                let all = args.contains("--all");
+                let store = args.contains("--store");
+                let memory = args.contains("--memory");
+                let year = parse_year(&mut args)?;
+                let format = parse_format(&mut args)?;
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let timeout = parse_timeout_arg(&mut args)?;
+                let compare = args.contains("--compare") || threshold.is_some();
+
+                AppArguments::Time {
+                    all,
+                    puzzle: args.opt_free_from_str()?,
+                    year,
+                    store,
+                    format,
+                    compare,
+                    threshold,
+                    memory,
+                    timeout,
+                }
+            }
+            Some("download") => AppArguments::Download {
+                puzzle: args.free_from_str()?,
+            },
+            Some("read") => AppArguments::Read {
+                puzzle: args.free_from_str()?,
+            },
+            Some("extract") => AppArguments::Extract {
+                puzzle: args.free_from_str()?,
+                block: args.opt_value_from_str("--block")?,
+                overwrite: args.contains("--overwrite"),
+            },
+            Some("migrate") => AppArguments::Migrate {
+                year: parse_year(&mut args)?,
+            },
+            Some("status") => AppArguments::Status {
+                year: parse_year(&mut args)?,
+                tests: args.contains("--tests"),
+                readme: args.contains("--readme"),
+            },
+            Some("scaffold") => AppArguments::Scaffold {
+                puzzle: args.free_from_str()?,
+                download: args.contains("--download"),
+                overwrite: args.contains("--overwrite"),
+                overwrite_data: args.contains("--overwrite-data"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| DEFAULT_TEMPLATE.into()),
+            },
+            Some("solve") => AppArguments::Solve {
+                puzzle: args.free_from_str()?,
+                release: args.contains("--release"),
+                submit: args.opt_value_from_str("--submit")?,
+                dhat: args.contains("--dhat"),
+                format: parse_format(&mut args)?,
+                timeout: parse_timeout_arg(&mut args)?,
+                watch: args.contains("--watch"),
+                force: args.contains("--force"),
+            },
+            #[cfg(feature = "today")]
+            Some("today") => AppArguments::Today,
+            Some(x) => {
+                eprintln!("Unknown command: {x}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("No command specified.");
+                process::exit(1);
+            }
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(app_args)
+    }
+
+    /// Parses the optional `--year` flag, defaulting to the configured year.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, pico_args::Error> {
+        Ok(args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(Year::current))
+    }
+
+    /// Parses the optional `--timeout <secs>` flag, defaulting to `AOC_TIMEOUT_SECS`.
+    fn parse_timeout_arg(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, pico_args::Error> {
+        Ok(args
+            .opt_value_from_fn("--timeout", |s| {
+                parse_timeout(s).ok_or("expecting a positive number of seconds")
+            })?
+            .or_else(timeout_from_env))
+    }
+
+    /// Parses the optional `--format` flag, defaulting to text.
+    fn parse_format(args: &mut pico_args::Arguments) -> Result<OutputFormat, pico_args::Error> {
+        Ok(args.opt_value_from_str("--format")?.unwrap_or_default())
+    }
+}
+
+/// Parses the command-line arguments and runs the requested command.
+pub fn run() {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok(args) => match args {
+            AppArguments::All {
+                year,
+                release,
+                format,
+                timeout,
+            } => all::handle(year, release, format, timeout),
+            AppArguments::Time {
+                puzzle,
+                year,
+                all,
+                store,
+                format,
+                compare,
+                threshold,
+                memory,
+                timeout,
+            } => time::handle(
+                puzzle, year, all, store, format, compare, threshold, memory, timeout,
+            ),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Extract {
+                puzzle,
+                block,
+                overwrite,
+            } => extract::handle(puzzle, block, overwrite),
+            AppArguments::Migrate { year } => migrate::handle(year),
+            AppArguments::Status {
+                year,
+                tests,
+                readme,
+            } => status::handle(year, tests, readme),
+            AppArguments::Scaffold {
+                puzzle,
+                download,
+                overwrite,
+                overwrite_data,
+                template,
+            } => {
+                // download first, the template reads the title and examples of the puzzle.
+                if download {
+                    download::handle(puzzle);
+                }
+                scaffold::handle(puzzle, overwrite, overwrite_data, download, &template);
+            }
+            AppArguments::Solve {
+                puzzle,
+                release,
+                dhat,
+                submit,
+                format,
+                timeout,
+                watch,
+                force,
+            } => {
+                if watch && submit.is_some() {
+                    eprintln!("--watch can not be combined with --submit.");
+                    std::process::exit(1);
+                }
+                if watch {
+                    watch::handle(puzzle, release, timeout);
+                } else {
+                    solve::handle(puzzle, release, dhat, submit, format, timeout, force);
+                }
+            }
+            #[cfg(feature = "today")]
+            AppArguments::Today => {
+                match PuzzleId::today() {
+                    Some(puzzle) => {
+                        download::handle(puzzle);
+                        scaffold::handle(puzzle, false, false, true, scaffold::DEFAULT_TEMPLATE);
+                        read::handle(puzzle)
+                    }
+                    None => {
+                        eprintln!(
+                            "`today` command can only be run on a day of advent in december. \
+                            Please use `scaffold` with a specific day."
+                        );
+                        process::exit(1)
+                    }
+                };
+            }
+        },
+    };
+}
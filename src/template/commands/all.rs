@@ -1,5 +1,13 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{process, time::Duration};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{OutputFormat, PuzzleId, Year, all_days_in, run_multi::run_multi};
+
+pub fn handle(year: Year, is_release: bool, format: OutputFormat, timeout: Option<Duration>) {
+    let puzzles = all_days_in(year)
+        .map(|day| PuzzleId::new(year, day))
+        .collect();
+    let run = run_multi(&puzzles, is_release, false, false, format, timeout);
+    if run.has_mismatch || run.has_panic {
+        process::exit(1);
+    }
 }
@@ -1,15 +1,28 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::{PuzzleId, aoc_cli};
 use std::process;
 
-pub fn handle(day: Day) {
+pub fn handle(puzzle: PuzzleId) {
     if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+        #[cfg(feature = "http")]
+        {
+            if let Err(e) = crate::template::aoc_client::download(puzzle) {
+                eprintln!("failed to download puzzle: {e}");
+                process::exit(1);
+            }
+            return;
+        }
+
+        #[cfg(not(feature = "http"))]
+        {
+            eprintln!(
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it \
+                or enable the \"http\" feature."
+            );
+            process::exit(1);
+        }
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if let Err(e) = aoc_cli::download(puzzle) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
@@ -0,0 +1,113 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    process,
+};
+
+use crate::template::description::{self, CodeBlock};
+use crate::template::{PuzzleId, examples, resolve_data_path};
+
+/// Lines of a code block shown when picking the example.
+const PREVIEW_LINES: usize = 5;
+
+pub fn handle(puzzle: PuzzleId, block: Option<usize>, overwrite: bool) {
+    let markdown = description::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let blocks = description::code_blocks(&markdown);
+    if blocks.is_empty() {
+        eprintln!("The description of {puzzle} does not contain any code blocks.");
+        process::exit(1);
+    }
+
+    let example_path = resolve_data_path("examples", puzzle.year, &format!("{}.txt", puzzle.day));
+    let manifest_path = resolve_data_path("examples", puzzle.year, &format!("{}.json", puzzle.day));
+    for path in [&example_path, &manifest_path] {
+        if !overwrite && !is_empty(path) {
+            eprintln!(
+                "\"{}\" already exists, pass --overwrite to replace it.",
+                path.display()
+            );
+            process::exit(1);
+        }
+    }
+
+    let index = match block {
+        Some(block) if (1..=blocks.len()).contains(&block) => block - 1,
+        Some(block) => {
+            eprintln!(
+                "There is no code block {block}, the description has {}.",
+                blocks.len()
+            );
+            process::exit(1);
+        }
+        None if blocks.len() == 1 => 0,
+        None => pick_block(&blocks),
+    };
+
+    let expected = description::expected_answers(&markdown);
+    if let Some(parent) = example_path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let result = fs::write(&example_path, &blocks[index].content)
+        .and_then(|()| fs::write(&manifest_path, examples::manifest("example", &expected)));
+    if let Err(e) = result {
+        eprintln!("Failed to write the example: {e}");
+        process::exit(1);
+    }
+
+    println!(
+        "Wrote code block {} to \"{}\"",
+        index + 1,
+        example_path.display()
+    );
+    println!("Wrote manifest \"{}\"", manifest_path.display());
+    for (part, expected) in (1..=2).zip(&expected) {
+        match expected {
+            Some(expected) => println!("  Part {part}: {expected}"),
+            None => println!("  Part {part}: no answer found"),
+        }
+    }
+    println!("---");
+    println!("🎄 Check the expected answers, they are the last emphasized values of each part.");
+}
+
+/// An example file left empty by `scaffold` may be replaced.
+fn is_empty(path: &Path) -> bool {
+    fs::read_to_string(path).map_or(true, |content| content.trim().is_empty())
+}
+
+/// Lists the code blocks and asks which one is the example, proposing the first one introduced
+/// as an example.
+fn pick_block(blocks: &[CodeBlock]) -> usize {
+    for (i, block) in blocks.iter().enumerate() {
+        let lines: Vec<&str> = block.content.lines().collect();
+        println!("[{}] part {}, {} line(s):", i + 1, block.part, lines.len());
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("    …");
+        }
+    }
+
+    let default = blocks.iter().position(|b| b.is_example).unwrap_or_default();
+
+    loop {
+        print!("Which block is the example? [{}] ", default + 1);
+        let _ = io::stdout().flush();
+
+        let mut answer = String::new();
+        match io::stdin().read_line(&mut answer) {
+            Ok(0) | Err(_) => return default,
+            Ok(_) if answer.trim().is_empty() => return default,
+            Ok(_) => match answer.trim().parse::<usize>() {
+                Ok(block) if (1..=blocks.len()).contains(&block) => return block - 1,
+                _ => println!("Enter a number from 1 to {}.", blocks.len()),
+            },
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{Day, PuzzleId, Year, get_data_path};
+
+const DATA_FOLDERS: [&str; 3] = ["inputs", "examples", "puzzles"];
+
+/// Moves the flat layout (`data/<folder>/<DD>.txt`, `src/bin/<DD>.rs`) of a single year
+/// into the per-year layout (`data/<year>/<folder>/<DD>.txt`, `src/bin/<year>_<DD>.rs`).
+pub fn handle(year: Year) {
+    let mut moved = 0;
+
+    for folder in DATA_FOLDERS {
+        let Ok(entries) = fs::read_dir(Path::new("data").join(folder)) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            // keep placeholders such as `.keep` in place.
+            if file_name.starts_with('.') || !entry.path().is_file() {
+                continue;
+            }
+
+            let target = get_data_path(folder, year, &file_name);
+            if move_file(&entry.path(), &target, None) {
+                moved += 1;
+            }
+        }
+    }
+
+    let Ok(entries) = fs::read_dir("src/bin") else {
+        eprintln!("Failed to read directory \"src/bin\".");
+        process::exit(1);
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(day) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u8>().ok())
+            .and_then(Day::new)
+            .filter(|day| year.has_day(*day))
+        else {
+            continue;
+        };
+
+        let puzzle = PuzzleId::new(year, day);
+        let target = format!("src/bin/{year}_{day}.rs");
+        if move_file(&path, Path::new(&target), Some(puzzle)) {
+            moved += 1;
+        }
+    }
+
+    println!("---");
+    println!("🎄 Migrated {moved} file(s) to the {year} layout.");
+}
+
+/// Moves a single file, refusing to overwrite an existing target.
+/// Solution modules additionally get their year pinned in the `solution!` invocation.
+fn move_file(source: &Path, target: &Path, puzzle: Option<PuzzleId>) -> bool {
+    if target.exists() {
+        eprintln!(
+            "Skipped \"{}\": \"{}\" already exists.",
+            source.display(),
+            target.display()
+        );
+        return false;
+    }
+
+    if let Some(parent) = target.parent()
+        && let Err(e) = fs::create_dir_all(parent)
+    {
+        eprintln!("Failed to create directory \"{}\": {e}", parent.display());
+        process::exit(1);
+    }
+
+    let result = match puzzle {
+        Some(puzzle) => fs::read_to_string(source)
+            .and_then(|contents| fs::write(target, pin_year(&contents, puzzle)))
+            .and_then(|()| fs::remove_file(source)),
+        None => fs::rename(source, target),
+    };
+
+    match result {
+        Ok(()) => {
+            println!("Moved \"{}\" to \"{}\"", source.display(), target.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to move \"{}\": {e}", source.display());
+            process::exit(1);
+        }
+    }
+}
+
+fn pin_year(contents: &str, puzzle: PuzzleId) -> String {
+    let invocation = "solution!(";
+    match contents.find(invocation) {
+        Some(pos) if !contents[pos..].starts_with(&format!("{invocation}year")) => {
+            let pos = pos + invocation.len();
+            format!(
+                "{}year = {}, {}",
+                &contents[..pos],
+                puzzle.year,
+                &contents[pos..]
+            )
+        }
+        _ => contents.to_string(),
+    }
+}
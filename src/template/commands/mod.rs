@@ -1,6 +1,10 @@
 pub mod all;
 pub mod download;
+pub mod extract;
+pub mod migrate;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+pub mod watch;
@@ -1,79 +1,309 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs, io,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{PuzzleId, description, examples, get_path_for_bin, resolve_data_path};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// The template used unless `--template` names another one.
+pub const DEFAULT_TEMPLATE: &str = "plain";
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
+/// Directory of user templates, e.g. `templates/dp.txt` for `--template dp`. User templates take
+/// precedence over the built-in ones of the same name.
+const USER_TEMPLATE_DIR: &str = "templates";
+
+/// Built-in templates by name.
+const PRESETS: [(&str, &str); 5] = [
+    ("plain", include_str!("../presets/plain.txt")),
+    ("parse", include_str!("../presets/parse.txt")),
+    ("grid", include_str!("../presets/grid.txt")),
+    ("graph", include_str!("../presets/graph.txt")),
+    ("both", include_str!("../presets/both.txt")),
+];
+
+/// Returns a template by name, looking in [`USER_TEMPLATE_DIR`] first.
+fn read_template(name: &str) -> Result<String, String> {
+    let path = Path::new(USER_TEMPLATE_DIR).join(format!("{name}.txt"));
+    if let Ok(template) = fs::read_to_string(&path) {
+        return Ok(template);
     }
-    file.truncate(true).write(true).open(path)
+
+    PRESETS
+        .iter()
+        .find(|(preset, _)| *preset == name)
+        .map(|(_, template)| (*template).to_string())
+        .ok_or_else(|| {
+            format!(
+                "Unknown template \"{name}\", expecting one of {} or a file in \"{USER_TEMPLATE_DIR}/\".",
+                template_names().join(", ")
+            )
+        })
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Names of the built-in and user templates.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = PRESETS.iter().map(|(name, _)| (*name).into()).collect();
+
+    if let Ok(entries) = fs::read_dir(USER_TEMPLATE_DIR) {
+        let mut user_names: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                Some(name.strip_suffix(".txt")?.to_string())
+            })
+            .filter(|name| !names.contains(name))
+            .collect();
+        user_names.sort();
+        names.extend(user_names);
+    }
+
+    names
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// The values of the placeholders of a template for a puzzle. The title and the answers to the
+/// examples are read from the stored description and the examples manifest if they exist.
+fn placeholders(puzzle: PuzzleId) -> Vec<(&'static str, String)> {
+    let description = description::read(puzzle).ok();
+    let title = description
+        .as_deref()
+        .and_then(description::title)
+        .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner()));
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+    let expected = examples::read(puzzle)
+        .ok()
+        .and_then(|examples| examples.into_iter().next())
+        .map(|example| example.expected)
+        .or_else(|| description.as_deref().map(description::expected_answers))
+        .unwrap_or_default();
+
+    vec![
+        ("%YEAR%", puzzle.year.to_string()),
+        ("%DAY_NUMBER%", puzzle.day.into_inner().to_string()),
+        ("%DAY%", puzzle.day.to_string()),
+        ("%TITLE%", title),
+        ("%EXAMPLE_PART_1%", to_expression(expected[0].as_deref())),
+        ("%EXAMPLE_PART_2%", to_expression(expected[1].as_deref())),
+    ]
+}
+
+/// Formats an expected answer as the `Option` a part returns, `None` unless it is a number.
+fn to_expression(answer: Option<&str>) -> String {
+    match answer {
+        Some(answer) if answer.parse::<u64>().is_ok() => format!("Some({answer})"),
+        _ => "None".into(),
+    }
+}
+
+fn render(template: &str, placeholders: &[(&str, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(template.to_string(), |template, (placeholder, value)| {
+            template.replace(placeholder, value)
+        })
+}
+
+/// What scaffolding did with a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Created,
+    Overwritten,
+    /// The file existed and was left as is.
+    Kept,
+}
+
+/// A file written by scaffolding.
+struct Scaffolded {
+    label: &'static str,
+    path: PathBuf,
+    contents: String,
+    overwrite: bool,
+}
+
+/// How to undo writing a file.
+enum Undo {
+    Remove(PathBuf),
+    Restore(PathBuf, Vec<u8>),
+}
+
+/// Writes a file unless it exists and may not be overwritten, returns what was done and how to
+/// undo it.
+fn write_file(file: &Scaffolded) -> io::Result<(Outcome, Option<Undo>)> {
+    let path = &file.path;
+
+    let (outcome, undo) = match fs::read(path) {
+        Ok(_) if !file.overwrite => return Ok((Outcome::Kept, None)),
+        Ok(previous) => (Outcome::Overwritten, Undo::Restore(path.clone(), previous)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            (Outcome::Created, Undo::Remove(path.clone()))
         }
+        Err(e) => return Err(e),
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
     }
+    fs::write(path, &file.contents)?;
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    Ok((outcome, Some(undo)))
+}
+
+/// Writes the files in order. If one fails, the files written before it are removed or
+/// restored and the error is returned with the label of the failing file.
+fn write_files(files: &[Scaffolded]) -> Result<Vec<Outcome>, (&'static str, &Path, io::Error)> {
+    let mut outcomes = vec![];
+    let mut undos = vec![];
+
+    for file in files {
+        match write_file(file) {
+            Ok((outcome, undo)) => {
+                outcomes.push(outcome);
+                undos.extend(undo);
+            }
+            Err(e) => {
+                for undo in undos.into_iter().rev() {
+                    let _ = match undo {
+                        Undo::Remove(path) => fs::remove_file(path),
+                        Undo::Restore(path, contents) => fs::write(path, contents),
+                    };
+                }
+                return Err((file.label, &file.path, e));
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+    Ok(outcomes)
+}
+
+/// Creates the solution of a puzzle from a template and its empty input and example files.
+/// Existing files are kept, the solution is replaced with `overwrite` and the data files, which
+/// are never truncated otherwise, with `overwrite_data`. An input that was just `downloaded` is
+/// always kept.
+pub fn handle(
+    puzzle: PuzzleId,
+    overwrite: bool,
+    overwrite_data: bool,
+    downloaded: bool,
+    template: &str,
+) {
+    let template = read_template(template).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let file_name = format!("{}.txt", puzzle.day);
+    let files = [
+        Scaffolded {
+            label: "module",
+            path: PathBuf::from(get_path_for_bin(puzzle)),
+            contents: render(&template, &placeholders(puzzle)),
+            overwrite,
+        },
+        Scaffolded {
+            label: "input",
+            path: resolve_data_path("inputs", puzzle.year, &file_name),
+            contents: String::new(),
+            overwrite: overwrite_data && !downloaded,
+        },
+        Scaffolded {
+            label: "example",
+            path: resolve_data_path("examples", puzzle.year, &file_name),
+            contents: String::new(),
+            overwrite: overwrite_data,
+        },
+    ];
+
+    let outcomes = match write_files(&files) {
+        Ok(outcomes) => outcomes,
+        Err((label, path, e)) => {
+            eprintln!(
+                "Failed to write {label} file \"{}\": {e}. Files created before were removed.",
+                path.display()
+            );
             process::exit(1);
         }
+    };
+
+    for (file, outcome) in files.iter().zip(outcomes) {
+        let (label, path) = (file.label, file.path.display());
+        match outcome {
+            Outcome::Created => println!("Created {label} file \"{path}\""),
+            Outcome::Overwritten => println!("Overwrote {label} file \"{path}\""),
+            Outcome::Kept => println!("Kept existing {label} file \"{path}\""),
+        }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {puzzle}` to run your solution.");
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{Outcome, PRESETS, Scaffolded, render, to_expression, write_files};
+
+    #[test]
+    fn renders_presets() {
+        let placeholders = [
+            ("%YEAR%", "2025".to_string()),
+            ("%DAY_NUMBER%", "8".to_string()),
+            ("%DAY%", "08".to_string()),
+            ("%TITLE%", "Day 8: Playground".to_string()),
+            ("%EXAMPLE_PART_1%", to_expression(Some("40"))),
+            ("%EXAMPLE_PART_2%", to_expression(Some("ABC"))),
+        ];
+
+        for (name, template) in PRESETS {
+            let module = render(template, &placeholders);
+            assert!(!module.contains('%'), "{name} has unknown placeholders");
+            assert!(module.starts_with("//! Day 8: Playground\n"));
+            assert!(module.contains("solution!(year = 2025, 8"));
+            assert!(module.contains("Some(40)"));
+        }
+    }
+
+    #[test]
+    fn keeps_and_restores_files() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let file = |name: &str, overwrite: bool| Scaffolded {
+            label: "test",
+            path: dir.join(name),
+            contents: "new".into(),
+            overwrite,
+        };
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), "input").unwrap();
+        fs::write(dir.join("module.rs"), "module").unwrap();
+
+        let outcomes = write_files(&[
+            file("module.rs", true),
+            file("input.txt", false),
+            file("example.txt", false),
+        ])
+        .unwrap();
+        assert_eq!(
+            outcomes,
+            vec![Outcome::Overwritten, Outcome::Kept, Outcome::Created]
+        );
+        assert_eq!(fs::read_to_string(dir.join("input.txt")).unwrap(), "input");
+        assert_eq!(fs::read_to_string(dir.join("module.rs")).unwrap(), "new");
+
+        // a directory can not be written as a file, which undoes the files written before.
+        fs::write(dir.join("module.rs"), "module").unwrap();
+        fs::create_dir_all(dir.join("blocked")).unwrap();
+        let files = [
+            file("module.rs", true),
+            file("other.txt", false),
+            file("blocked", true),
+        ];
+        let (_, path, _) = write_files(&files).unwrap_err();
+        assert_eq!(path, dir.join("blocked"));
+        assert_eq!(fs::read_to_string(dir.join("module.rs")).unwrap(), "module");
+        assert!(!dir.join("other.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
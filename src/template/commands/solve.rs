@@ -1,21 +1,50 @@
-use std::process::{Command, Stdio};
+use std::io;
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
 
-use crate::template::Day;
+use crate::template::run_multi::child_commands;
+use crate::template::{OutputFormat, PuzzleId, get_bin_name};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+    force: bool,
+) {
+    // a failing example most likely means a wrong answer, which locks submissions for a while.
+    if submit_part.is_some() && !force {
+        // machine-readable formats keep stdout to themselves.
+        if format.is_text() {
+            println!("Running the example tests before submitting…");
+        } else {
+            eprintln!("Running the example tests before submitting…");
+        }
+        if !run_example_tests(puzzle, release, false) {
+            eprintln!(
+                "The example tests fail, nothing was submitted. Pass --force to submit anyway."
+            );
+            process::exit(1);
+        }
+    }
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), get_bin_name(puzzle)];
 
     if dhat {
-        cmd_args.extend([
-            "--profile".to_string(),
-            "dhat".to_string(),
-            "--features".to_string(),
-            "dhat-heap".to_string(),
-        ]);
+        cmd_args.push("--profile".to_string());
+        cmd_args.push("dhat".to_string());
     } else if release {
         cmd_args.push("--release".to_string());
     }
 
+    let features = child_commands::features(dhat);
+    if !features.is_empty() {
+        cmd_args.push("--features".to_string());
+        cmd_args.push(features);
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,6 +52,16 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if !format.is_text() {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs_f64().to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +69,34 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().unwrap();
+
+    // forward failures, e.g. a part that no longer matches its accepted answer.
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// Runs the tests of a solution, including those of the examples in its manifest, and returns
+/// whether they pass. The output of the tests goes to stderr, so that it does not mix with reports
+/// printed as JSON, and is not shown at all with `is_quiet`.
+pub(crate) fn run_example_tests(puzzle: PuzzleId, release: bool, is_quiet: bool) -> bool {
+    let bin_name = get_bin_name(puzzle);
+    let mut args = vec!["test", "--quiet", "--bin", &bin_name];
+    if release {
+        args.push("--release");
+    }
+
+    let (stdout, stderr) = if is_quiet {
+        (Stdio::null(), Stdio::null())
+    } else {
+        (Stdio::from(io::stderr()), Stdio::inherit())
+    };
+
+    Command::new("cargo")
+        .args(&args)
+        .stdout(stdout)
+        .stderr(stderr)
+        .status()
+        .is_ok_and(|status| status.success())
 }
@@ -0,0 +1,47 @@
+use std::process;
+
+use crate::template::commands::solve::run_example_tests;
+use crate::template::status::{self, DayStatus};
+use crate::template::timings::Timings;
+use crate::template::{Answers, PuzzleId, Year, all_days_in};
+
+/// Prints the status of every day of a year. With `run_tests`, the tests of scaffolded days are
+/// run as well, with `update_readme` the checklist of the readme is regenerated.
+pub fn handle(year: Year, run_tests: bool, update_readme: bool) {
+    // the readme has a single checklist, the one of the configured year.
+    if update_readme && year != Year::current() {
+        eprintln!(
+            "The checklist of the readme is the one of {}, --readme can not be used for {year}.",
+            Year::current()
+        );
+        process::exit(1);
+    }
+
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    let timings = Timings::read_from_file();
+
+    let statuses: Vec<DayStatus> = all_days_in(year)
+        .map(|day| {
+            let mut status = DayStatus::read(PuzzleId::new(year, day), &answers, &timings);
+            if run_tests && status.is_scaffolded {
+                status.tests = Some(run_example_tests(status.puzzle, false, true));
+            }
+            status
+        })
+        .collect();
+
+    println!("{}", status::format_table(&statuses));
+
+    if update_readme {
+        match status::update_readme(&statuses) {
+            Ok(()) => println!("Updated the checklist of the readme."),
+            Err(e) => {
+                eprintln!("Failed to update the readme: {e:?}");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -1,35 +1,67 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process, process::Command, time::Duration};
 
+use crate::template::answers::now;
+use crate::template::compare::{Change, Comparison, compare};
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, OutputFormat, PuzzleId, Year, all_days_in, readme_benchmarks,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    puzzle: Option<PuzzleId>,
+    year: Year,
+    run_all: bool,
+    store: bool,
+    format: OutputFormat,
+    compare_runs: bool,
+    threshold: Option<f64>,
+    memory: bool,
+    timeout: Option<Duration>,
+) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let puzzles_to_run = puzzle.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            let puzzles = all_days_in(year).map(|day| PuzzleId::new(year, day));
+            if run_all || compare_runs || memory {
+                puzzles.collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                puzzles
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |puzzle| HashSet::from([puzzle]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // the profiler slows the parts down, so memory runs are not benched.
+    let run = run_multi(&puzzles_to_run, true, !memory, memory, format, timeout);
+    let timings = run.timings.unwrap();
+
+    let has_regression = compare_runs && !memory && {
+        let comparisons = compare(&stored_timings, &timings);
+        print_comparisons(&comparisons, format);
+        threshold.is_some_and(|threshold| comparisons.iter().any(|c| c.exceeds(threshold)))
+    };
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
+        let merged_timings = if memory {
+            stored_timings.merge_memory(&timings)
+        } else {
+            stored_timings.record(&timings, current_commit().as_deref(), now())
+        };
         merged_timings.store_file().unwrap();
 
-        println!();
         match readme_benchmarks::update(merged_timings) {
+            // keep machine-readable output on stdout clean.
+            Ok(()) if !format.is_text() => {
+                eprintln!("Stored updated benchmarks.");
+            }
             Ok(()) => {
+                println!();
                 println!("Stored updated benchmarks.");
             }
             Err(_) => {
@@ -37,4 +69,71 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if run.has_mismatch || run.has_panic || has_regression {
+        process::exit(1);
+    }
+}
+
+/// Print how each part changed since the previous stored run.
+/// Machine-readable formats keep stdout to themselves, so the comparison goes to stderr.
+fn print_comparisons(comparisons: &[Comparison], format: OutputFormat) {
+    let mut lines = vec![
+        String::new(),
+        format!("{ANSI_BOLD}Compared to the previous run{ANSI_RESET}"),
+        "----------------------------".into(),
+    ];
+
+    if comparisons.is_empty() {
+        lines.push("No previous timings to compare to.".into());
+    }
+
+    // only qualify days with their year if the comparison spans several years.
+    let is_multi_year = comparisons
+        .windows(2)
+        .any(|w| w[0].puzzle.year != w[1].puzzle.year);
+
+    for comparison in comparisons {
+        let marker = match comparison.change {
+            Change::Regression => "▲",
+            Change::Improvement => "▼",
+            Change::Unchanged | Change::Unknown => " ",
+        };
+        let label = if is_multi_year {
+            comparison.puzzle.to_string()
+        } else {
+            format!("Day {}", comparison.puzzle.day)
+        };
+        lines.push(format!("{marker} {label} | {comparison}"));
+    }
+
+    for line in lines {
+        if format.is_text() {
+            println!("{line}");
+        } else {
+            eprintln!("{line}");
+        }
+    }
+}
+
+/// Returns the short hash of `HEAD`, suffixed with `-dirty` if tracked files have uncommitted changes.
+fn current_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let hash = git(&["rev-parse", "--short", "HEAD"])?;
+    let is_dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+
+    Some(if is_dirty {
+        format!("{hash}-dirty")
+    } else {
+        hash
+    })
 }
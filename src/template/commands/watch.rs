@@ -0,0 +1,154 @@
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::commands::solve::run_example_tests;
+use crate::template::run_multi::child_commands;
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, OutputFormat, PartReport, PuzzleId, Step, get_path_for_bin,
+    resolve_data_path,
+};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Runs a solution whenever its source, examples or input change: the example tests first, then
+/// the real input if they pass. Runs until it is interrupted.
+pub fn handle(puzzle: PuzzleId, release: bool, timeout: Option<Duration>) {
+    let mut previous: Option<Vec<PartReport>> = None;
+
+    loop {
+        let snapshot = modified_times(puzzle);
+
+        print!("{CLEAR_SCREEN}");
+        println!(
+            "{ANSI_ITALIC}Watching {puzzle}, press Ctrl-C to stop.{ANSI_RESET}\n\n{ANSI_BOLD}Examples{ANSI_RESET}"
+        );
+
+        if run_example_tests(puzzle, release, false) {
+            println!("\n{ANSI_BOLD}Input{ANSI_RESET}");
+            match child_commands::run_solution(
+                puzzle,
+                false,
+                release,
+                false,
+                OutputFormat::Text,
+                timeout,
+            ) {
+                Ok(reports) => {
+                    if let Some(previous) = &previous {
+                        println!("\n{ANSI_BOLD}Changes{ANSI_RESET}");
+                        for line in diff_answers(previous, &reports) {
+                            println!("{line}");
+                        }
+                    }
+                    previous = Some(reports);
+                }
+                Err(e) => eprintln!("Failed to run {puzzle}: {e:?}"),
+            }
+        } else {
+            println!("\nThe examples fail, the input is not run.");
+        }
+
+        while modified_times(puzzle) == snapshot {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Returns when the files of a puzzle were modified: its source, input and every example file
+/// of the day, e.g. `08.txt`, `08-2.txt` and the manifest `08.json`.
+fn modified_times(puzzle: PuzzleId) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let day = puzzle.day.to_string();
+    let example_dir = resolve_data_path("examples", puzzle.year, &format!("{day}.txt"))
+        .parent()
+        .map(PathBuf::from)
+        .unwrap_or_default();
+
+    let mut paths = vec![
+        PathBuf::from(get_path_for_bin(puzzle)),
+        resolve_data_path("inputs", puzzle.year, &format!("{day}.txt")),
+    ];
+    if let Ok(entries) = fs::read_dir(example_dir) {
+        let mut examples: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&day))
+            })
+            .collect();
+        examples.sort();
+        paths.extend(examples);
+    }
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Describes how the answers of the parts changed since the previous run.
+fn diff_answers(previous: &[PartReport], current: &[PartReport]) -> Vec<String> {
+    let answer = |reports: &[PartReport], part: u8| {
+        reports
+            .iter()
+            .find(|report| report.step == Step::Part(part))
+            .and_then(|report| report.answer.clone())
+    };
+
+    (1..=2)
+        .filter_map(|part| {
+            let change = match (answer(previous, part), answer(current, part)) {
+                (None, None) => return None,
+                (Some(before), Some(after)) if before == after => format!("{after} (unchanged)"),
+                (Some(before), Some(after)) => format!("{after} (was {before})"),
+                (None, Some(after)) => format!("{after} (new)"),
+                (Some(before), None) => format!("no answer (was {before})"),
+            };
+            Some(format!("Part {part}: {change}"))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::diff_answers;
+    use crate::puzzle;
+    use crate::template::{PartReport, Step};
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            answer: answer.map(String::from),
+            ..PartReport::empty(puzzle!(2025, 8), Step::Part(part))
+        }
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = [report(1, Some("40")), report(2, None)];
+        assert_eq!(
+            diff_answers(&previous, &[report(1, Some("40")), report(2, Some("7"))]),
+            vec!["Part 1: 40 (unchanged)", "Part 2: 7 (new)"]
+        );
+        assert_eq!(
+            diff_answers(&previous, &[report(1, Some("41"))]),
+            vec!["Part 1: 41 (was 40)"]
+        );
+        assert_eq!(
+            diff_answers(&previous, &[report(1, None)]),
+            vec!["Part 1: no answer (was 40)"]
+        );
+    }
+}
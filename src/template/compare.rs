@@ -0,0 +1,242 @@
+/// Detects performance changes between two timed runs.
+use std::fmt::Display;
+
+use crate::template::timings::Timings;
+use crate::template::{BenchStats, PuzzleId, Step};
+
+/// Outcome of comparing a step against its previous run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    Regression,
+    Improvement,
+    /// The difference is not statistically significant.
+    Unchanged,
+    /// One of the runs lacks the samples to test for significance.
+    Unknown,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Change::Regression => "regression",
+            Change::Improvement => "improvement",
+            Change::Unchanged => "unchanged",
+            Change::Unknown => "unknown",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub puzzle: PuzzleId,
+    pub step: Step,
+    pub previous: BenchStats,
+    pub current: BenchStats,
+    pub change: Change,
+}
+
+impl Comparison {
+    pub fn new(puzzle: PuzzleId, step: Step, previous: BenchStats, current: BenchStats) -> Self {
+        let change = significance(&previous, &current);
+        Self {
+            puzzle,
+            step,
+            previous,
+            current,
+            change,
+        }
+    }
+
+    /// Relative change of the mean duration in percent, positive when the part got slower.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn relative_change(&self) -> f64 {
+        let previous = self.previous.mean.as_nanos() as f64;
+        let current = self.current.mean.as_nanos() as f64;
+        if previous == 0.0 {
+            return 0.0;
+        }
+        (current - previous) / previous * 100.0
+    }
+
+    /// Returns `true` for a significant regression that slowed the part down by more than
+    /// `threshold` percent.
+    pub fn exceeds(&self, threshold: f64) -> bool {
+        self.change == Change::Regression && self.relative_change() > threshold
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {:.1?} → {:.1?} ({:+.1}%) {}",
+            self.step,
+            self.previous.mean,
+            self.current.mean,
+            self.relative_change(),
+            self.change
+        )
+    }
+}
+
+/// Compare every timed step of `current` against its latest timing in `previous`.
+pub fn compare(previous: &Timings, current: &Timings) -> Vec<Comparison> {
+    current
+        .data
+        .iter()
+        .filter_map(|timing| Some((timing, previous.get(timing.puzzle)?)))
+        .flat_map(|(timing, stored)| {
+            [
+                (Step::Parse, &stored.parse, &timing.parse),
+                (Step::Part(1), &stored.part_1, &timing.part_1),
+                (Step::Part(2), &stored.part_2, &timing.part_2),
+                (Step::Both, &stored.combined, &timing.combined),
+            ]
+            .into_iter()
+            .filter_map(|(step, previous, current)| {
+                Some(Comparison::new(
+                    timing.puzzle,
+                    step,
+                    previous.clone()?,
+                    current.clone()?,
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Welch's t-test on the means of both runs at a 95% confidence level.
+#[allow(clippy::cast_precision_loss)]
+fn significance(previous: &BenchStats, current: &BenchStats) -> Change {
+    if previous.samples < 2 || current.samples < 2 {
+        return Change::Unknown;
+    }
+
+    let (n1, n2) = (previous.samples as f64, current.samples as f64);
+    let (m1, m2) = (
+        previous.mean.as_nanos() as f64,
+        current.mean.as_nanos() as f64,
+    );
+    let v1 = (previous.stddev.as_nanos() as f64).powi(2) / n1;
+    let v2 = (current.stddev.as_nanos() as f64).powi(2) / n2;
+
+    let is_significant = if v1 + v2 == 0.0 {
+        m1 != m2
+    } else {
+        let t = (m2 - m1) / (v1 + v2).sqrt();
+        // Welch–Satterthwaite degrees of freedom.
+        let df = (v1 + v2).powi(2) / (v1.powi(2) / (n1 - 1.0) + v2.powi(2) / (n2 - 1.0));
+        t.abs() > critical_value(df)
+    };
+
+    match (is_significant, m2 > m1) {
+        (false, _) => Change::Unchanged,
+        (true, true) => Change::Regression,
+        (true, false) => Change::Improvement,
+    }
+}
+
+/// Two-sided 95% critical value of Student's t-distribution, using the first-order expansion
+/// around the normal distribution: `z + (z³ + z) / 4df`.
+fn critical_value(df: f64) -> f64 {
+    const Z: f64 = 1.959_964;
+    if !df.is_finite() {
+        return Z;
+    }
+    Z + (Z.powi(3) + Z) / (4.0 * df)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Change, Comparison, compare};
+    use crate::puzzle;
+    use crate::template::timings::{Timing, Timings};
+    use crate::template::{BenchStats, Step};
+
+    fn stats(mean_nanos: u64, stddev_nanos: u64, samples: u128) -> BenchStats {
+        BenchStats {
+            stddev: Duration::from_nanos(stddev_nanos),
+            ..BenchStats::from_duration(Duration::from_nanos(mean_nanos), samples)
+        }
+    }
+
+    fn timings(part_2: Option<BenchStats>) -> Timings {
+        Timings {
+            data: vec![Timing {
+                puzzle: puzzle!(2025, 8),
+                parse: None,
+                part_1: Some(stats(1000, 10, 100)),
+                part_2,
+                combined: None,
+                memory: vec![],
+                timeouts: vec![],
+                total_nanos: 0_f64,
+            }],
+            history: vec![],
+        }
+    }
+
+    #[test]
+    fn detects_significant_changes() {
+        let slower = Comparison::new(
+            puzzle!(2025, 8),
+            Step::Part(1),
+            stats(1000, 10, 100),
+            stats(1100, 10, 100),
+        );
+        assert_eq!(slower.change, Change::Regression);
+        assert!((slower.relative_change() - 10.0).abs() < 1e-9);
+        assert!(slower.exceeds(5.0));
+        assert!(!slower.exceeds(15.0));
+
+        let faster = Comparison::new(
+            puzzle!(2025, 8),
+            Step::Part(1),
+            stats(1000, 10, 100),
+            stats(900, 10, 100),
+        );
+        assert_eq!(faster.change, Change::Improvement);
+        assert!(!faster.exceeds(5.0));
+    }
+
+    #[test]
+    fn ignores_noise() {
+        let noisy = Comparison::new(
+            puzzle!(2025, 8),
+            Step::Part(1),
+            stats(1000, 500, 10),
+            stats(1100, 500, 10),
+        );
+        assert_eq!(noisy.change, Change::Unchanged);
+        assert!(!noisy.exceeds(5.0));
+    }
+
+    #[test]
+    fn needs_samples() {
+        let legacy = Comparison::new(
+            puzzle!(2025, 8),
+            Step::Part(1),
+            stats(1000, 0, 0),
+            stats(2000, 10, 100),
+        );
+        assert_eq!(legacy.change, Change::Unknown);
+    }
+
+    #[test]
+    fn compares_timed_parts() {
+        let previous = timings(None);
+        let current = timings(Some(stats(1000, 10, 100)));
+
+        let comparisons = compare(&previous, &current);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].step, Step::Part(1));
+        assert_eq!(comparisons[0].change, Change::Unchanged);
+
+        assert!(compare(&Timings::default(), &current).is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+/// Parameters of solutions that differ between the real input and examples, e.g. the number of
+/// steps to simulate, see `solution!(…, params = { … }, example_params = { … })`.
+use std::{fmt::Display, str::FromStr};
+
+use crate::template::runner::RunOptions;
+
+/// The parameters a solution declares for the real input and for examples.
+pub struct Params {
+    pub real: &'static [(&'static str, &'static dyn Display)],
+    pub example: &'static [(&'static str, &'static dyn Display)],
+}
+
+/// What the parts of a solution know about the input they run on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Context {
+    is_example: bool,
+    params: Vec<(String, String)>,
+}
+
+impl Context {
+    /// Selects the parameters for the kind of input that is run, with those passed in `options`
+    /// (e.g. by an example in the manifest) taking precedence.
+    pub fn new(params: &Params, options: &RunOptions) -> Self {
+        let mut context = if options.is_example {
+            Self::example(params)
+        } else {
+            Self::real(params)
+        };
+
+        for (name, value) in &options.params {
+            context.params.retain(|(other, _)| other != name);
+            context.params.push((name.clone(), value.clone()));
+        }
+
+        context
+    }
+
+    /// The context of the real input.
+    pub fn real(params: &Params) -> Self {
+        Self {
+            is_example: false,
+            params: to_strings(params.real),
+        }
+    }
+
+    /// The context of an example, e.g. to call the parts in tests.
+    pub fn example(params: &Params) -> Self {
+        Self {
+            is_example: true,
+            params: to_strings(params.example),
+        }
+    }
+
+    pub fn is_example(&self) -> bool {
+        self.is_example
+    }
+
+    /// Returns a parameter, panics if it is missing or can not be parsed.
+    #[track_caller]
+    pub fn param<T: FromStr>(&self, name: &str) -> T {
+        let Some((_, value)) = self.params.iter().find(|(other, _)| other == name) else {
+            let kind = if self.is_example {
+                "examples"
+            } else {
+                "the input"
+            };
+            panic!("parameter \"{name}\" is not set for {kind}");
+        };
+
+        let Ok(value) = value.parse() else {
+            panic!("parameter \"{name}\" has an invalid value \"{value}\"");
+        };
+        value
+    }
+}
+
+fn to_strings(params: &[(&str, &dyn Display)]) -> Vec<(String, String)> {
+    params
+        .iter()
+        .map(|(name, value)| ((*name).to_string(), value.to_string()))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Context, Params};
+    use crate::template::runner::RunOptions;
+
+    const PARAMS: Params = Params {
+        real: &[("connections", &1000), ("mode", &"fast")],
+        example: &[("connections", &10)],
+    };
+
+    #[test]
+    fn selects_params() {
+        let real = Context::new(&PARAMS, &RunOptions::default());
+        assert!(!real.is_example());
+        assert_eq!(real.param::<usize>("connections"), 1000);
+        assert_eq!(real.param::<String>("mode"), "fast");
+
+        let example = Context::example(&PARAMS);
+        assert!(example.is_example());
+        assert_eq!(example.param::<usize>("connections"), 10);
+    }
+
+    #[test]
+    fn overrides_params() {
+        let options = RunOptions {
+            is_example: true,
+            params: vec![("connections".into(), "5".into())],
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            Context::new(&PARAMS, &options).param::<u32>("connections"),
+            5
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "parameter \"mode\" is not set for examples")]
+    fn panics_on_missing_params() {
+        Context::example(&PARAMS).param::<String>("mode");
+    }
+}
@@ -0,0 +1,117 @@
+/// A global allocator that counts allocations, installed by `solution!` with the `count-alloc`
+/// feature. Much cheaper than `dhat-heap`, so it can stay enabled while benchmarking.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::template::MemoryStats;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps the system allocator and counts every allocation.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    let size = size as u64;
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    CURRENT_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+}
+
+/// Counters at the start of a measurement, see [`Snapshot::start`].
+pub struct Snapshot {
+    allocations: u64,
+    allocated_bytes: u64,
+    current_bytes: u64,
+}
+
+impl Snapshot {
+    /// Starts measuring, resetting the peak to the bytes allocated right now.
+    pub fn start() -> Self {
+        let current_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(current_bytes, Ordering::Relaxed);
+
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            current_bytes,
+        }
+    }
+
+    /// Returns the heap usage since the snapshot was taken.
+    pub fn stop(&self) -> MemoryStats {
+        MemoryStats {
+            peak_bytes: PEAK_BYTES
+                .load(Ordering::Relaxed)
+                .saturating_sub(self.current_bytes),
+            total_allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            total_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - self.allocated_bytes,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use super::{CountingAlloc, Snapshot};
+
+    #[test]
+    fn counts_allocations() {
+        let snapshot = Snapshot::start();
+
+        unsafe {
+            let a = CountingAlloc.alloc(Layout::from_size_align(64, 8).unwrap());
+            let b = CountingAlloc.alloc_zeroed(Layout::from_size_align(32, 8).unwrap());
+            CountingAlloc.dealloc(a, Layout::from_size_align(64, 8).unwrap());
+            let b = CountingAlloc.realloc(b, Layout::from_size_align(32, 8).unwrap(), 128);
+            CountingAlloc.dealloc(b, Layout::from_size_align(128, 8).unwrap());
+        }
+
+        let memory = snapshot.stop();
+        assert_eq!(memory.total_allocations, 3);
+        assert_eq!(memory.total_bytes, 224);
+        assert_eq!(memory.peak_bytes, 128);
+    }
+}
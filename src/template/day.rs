@@ -2,13 +2,10 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
-
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+use crate::template::Year;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Whether a year actually has that day is decided by [`Year::day_count`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -45,15 +42,10 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if a puzzle is released today, `None` otherwise.
+    /// See [`crate::template::PuzzleId::today`].
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        crate::template::PuzzleId::today().map(|puzzle| puzzle.day)
     }
 }
 
@@ -77,6 +69,8 @@ impl PartialOrd<u8> for Day {
 
 /* -------------------------------------------------------------------------- */
 
+/// Parses a day number between 1 and 25, whether a year has that day is checked by
+/// [`crate::template::PuzzleId`].
 impl FromStr for Day {
     type Err = DayFromStrError;
 
@@ -100,20 +94,33 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of the configured year.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of the given year.
+pub fn all_days_in(year: Year) -> AllDays {
+    AllDays::for_year(year)
+}
+
+/// An iterator that yields every day of advent from the 1st to the last day of a year.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::for_year(Year::current())
+    }
+
+    pub fn for_year(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.day_count(),
+        }
     }
 }
 
@@ -121,10 +128,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and `Year::day_count` never exceeds 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -154,7 +161,8 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{Day, all_days, all_days_in};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
@@ -174,6 +182,22 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(12)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_for_full_calendar() {
+        let days: Vec<Day> = all_days_in(year!(2024)).collect();
+
+        assert_eq!(days.len(), 25);
+        assert_eq!(days.last(), Some(&Day(25)));
+    }
+
+    #[test]
+    fn parses_days_of_any_calendar() {
+        assert_eq!("12".parse::<Day>().unwrap(), Day(12));
+        assert_eq!("25".parse::<Day>().unwrap(), Day(25));
+        assert!("26".parse::<Day>().is_err());
+        assert!("0".parse::<Day>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,295 @@
+/// Puzzle descriptions stored by `read` and `download` in `data/puzzles/DD.md`, and the examples
+/// they contain: the code blocks and the emphasized answers of each part.
+use std::fs;
+
+use crate::template::{PuzzleId, resolve_data_path};
+
+/// A code block of a description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The part whose description contains the block.
+    pub part: u8,
+    /// The lines of the block, with a trailing newline.
+    pub content: String,
+    /// Whether the text right before the block mentions an example.
+    pub is_example: bool,
+}
+
+/// Reads the stored description of a puzzle as markdown, converting it if it was stored as HTML.
+pub fn read(puzzle: PuzzleId) -> Result<String, String> {
+    let path = resolve_data_path("puzzles", puzzle.year, &format!("{}.md", puzzle.day));
+    let description = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "Could not read \"{}\": {e}. Run `cargo read {puzzle}` first.",
+            path.display()
+        )
+    })?;
+
+    if description.contains("<pre") || description.contains("<article") {
+        Ok(html_to_markdown(&description))
+    } else {
+        Ok(description)
+    }
+}
+
+/// Returns the title of a puzzle, e.g. `Day 1: Historian Hysteria` for its heading
+/// `--- Day 1: Historian Hysteria ---`.
+pub fn title(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        let start = line.find("--- Day ")?;
+        let title = line[start + "--- ".len()..]
+            .trim_end()
+            .strip_suffix("---")?;
+        Some(title.trim().to_string())
+    })
+}
+
+/// Returns the fenced code blocks of a markdown description in order.
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut part = 1;
+    let mut preceding_text = String::new();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (&mut block, line.trim_start().starts_with("```")) {
+            (None, true) => block = Some(String::new()),
+            (None, false) => {
+                if is_part_two_heading(line) {
+                    part = 2;
+                }
+                if line.trim().is_empty() {
+                    continue;
+                }
+                preceding_text = line.to_lowercase();
+            }
+            (Some(_), true) => blocks.push(CodeBlock {
+                part,
+                content: block.take().unwrap_or_default(),
+                is_example: preceding_text.contains("example"),
+            }),
+            (Some(content), false) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Returns the answers of part 1 and 2 to the examples, which are the last emphasized code
+/// (e.g. `` `*11*` ``) of each part's text.
+pub fn expected_answers(markdown: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    let mut part = 0;
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if in_block {
+            continue;
+        }
+        if is_part_two_heading(line) {
+            part = 1;
+        }
+        if let Some(answer) = emphasized_code(line).pop() {
+            answers[part] = Some(answer);
+        }
+    }
+
+    answers
+}
+
+fn is_part_two_heading(line: &str) -> bool {
+    line.contains("--- Part Two ---")
+}
+
+/// Returns the emphasized code of a line, written as `` `*11*` `` or `` *`11`* ``.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = line;
+
+    while let Some(start) = [rest.find("`*"), rest.find("*`")]
+        .into_iter()
+        .flatten()
+        .min()
+    {
+        let opening = &rest[start..start + 2];
+        let closing: String = opening.chars().rev().collect();
+
+        let value_start = start + opening.len();
+        let Some(len) = rest[value_start..].find(&closing) else {
+            break;
+        };
+        let value = rest[value_start..value_start + len].trim();
+        if !value.is_empty() {
+            values.push(value.to_string());
+        }
+        rest = &rest[value_start + len + closing.len()..];
+    }
+
+    values
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Converts the `<article>` elements of a page into markdown, keeping code blocks
+/// and emphasis so that examples and answers can be picked up later on.
+pub fn html_to_markdown(html: &str) -> String {
+    let articles: Vec<&str> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|s| s.split_once('>').map(|(_, rest)| rest))
+        .filter_map(|s| s.split("</article>").next())
+        .collect();
+
+    let body = if articles.is_empty() {
+        html.to_string()
+    } else {
+        articles.join("\n")
+    };
+
+    let mut out = String::new();
+    let mut rest = body.as_str();
+    let mut in_pre = false;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+        let is_closing = tag.starts_with('/');
+
+        match (name, is_closing) {
+            ("h2", false) => out.push_str("\n## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            // code blocks are kept verbatim, they are the inputs of examples.
+            ("code" | "em", _) if in_pre => {}
+            ("code", _) => out.push('`'),
+            ("em", _) => out.push('*'),
+            _ => {}
+        }
+    }
+
+    out.push_str(&decode_entities(rest));
+    out.trim().to_string() + "\n"
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{CodeBlock, code_blocks, expected_answers, html_to_markdown, title};
+
+    const DESCRIPTION: &str = "\
+## --- Day 1: Test ---
+
+The lists look like this:
+
+```
+a
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+In this example, the first distance is *`2`* and the total is `*11*`.
+
+## --- Part Two ---
+
+Here, the similarity score is `*31*`.
+";
+
+    #[test]
+    fn converts_puzzle_html() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>\
+            <p>For <em>example</em>, <code>a &lt; b</code>:</p>\
+            <pre><code>1\n<em>2</em>\n</code></pre></article></main>";
+
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Test ---\n\nFor *example*, `a < b`:\n\n```\n1\n2\n```\n"
+        );
+    }
+
+    #[test]
+    fn finds_titles() {
+        assert_eq!(title(DESCRIPTION), Some("Day 1: Test".into()));
+        assert_eq!(
+            title("\\--- Day 12: Christmas Tree Farm ---\n----------\n"),
+            Some("Day 12: Christmas Tree Farm".into())
+        );
+        assert_eq!(title("## --- Part Two ---"), None);
+    }
+
+    #[test]
+    fn finds_code_blocks() {
+        assert_eq!(
+            code_blocks(DESCRIPTION),
+            vec![
+                CodeBlock {
+                    part: 1,
+                    content: "a\n".into(),
+                    is_example: false,
+                },
+                CodeBlock {
+                    part: 1,
+                    content: "3   4\n4   3\n".into(),
+                    is_example: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_expected_answers() {
+        assert_eq!(
+            expected_answers(DESCRIPTION),
+            [Some("11".into()), Some("31".into())]
+        );
+
+        let without_part_two = DESCRIPTION.split("## --- Part Two").next().unwrap();
+        assert_eq!(
+            expected_answers(without_part_two),
+            [Some("11".into()), None]
+        );
+    }
+}
@@ -0,0 +1,274 @@
+/// Example inputs of a puzzle with their expected answers, listed in a manifest next to the
+/// example files, e.g. `data/2025/examples/08.json`:
+///
+/// ```json
+/// {
+///   "examples": [
+///     { "name": "small", "part_1": 40, "part_2": "25272", "params": { "connections": 10 } },
+///     { "name": "second", "file": "08-2.txt", "part_2": 7 },
+///     { "name": "inline", "input": "1,2,3\n4,5,6\n", "part_1": 3 }
+///   ]
+/// }
+/// ```
+///
+/// Examples read `file` (the day's `08.txt` by default) or take their `input` inline. Expected
+/// answers and parameters are strings or numbers, answers larger than 2^53 must be strings.
+/// `build.rs` generates a test per example for every solution, see [`crate::example_tests`].
+use std::{collections::HashMap, fs};
+
+use tinyjson::JsonValue;
+
+use crate::template::registry::Solution;
+use crate::template::runner::RunOptions;
+use crate::template::{PuzzleId, Step, resolve_data_path};
+
+/// An example input of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    /// Expected answers of part 1 and 2, `None` for parts the example does not cover.
+    pub expected: [Option<String>; 2],
+    /// Named parameters of the example, e.g. a smaller size than the real input uses.
+    pub params: Vec<(String, String)>,
+}
+
+/// Reads the examples listed in the manifest of a puzzle.
+pub fn read(puzzle: PuzzleId) -> Result<Vec<Example>, String> {
+    let read_file = |file: &str| {
+        let path = resolve_data_path("examples", puzzle.year, file);
+        fs::read_to_string(&path).map_err(|e| format!("Could not read \"{}\": {e}", path.display()))
+    };
+
+    let manifest = read_file(&format!("{}.json", puzzle.day))?;
+    parse_manifest(&manifest, &format!("{}.txt", puzzle.day), read_file)
+}
+
+/// Runs a solution on one of its examples and panics unless it reproduces the expected answers.
+/// Called by the tests generated for each example.
+pub fn run_example(solution: &Solution, name: &str) {
+    let examples = read(solution.puzzle).unwrap_or_else(|e| panic!("{e}"));
+    let example = examples
+        .iter()
+        .find(|example| example.name == name)
+        .unwrap_or_else(|| panic!("There is no example named \"{name}\"."));
+
+    let options = RunOptions {
+        is_example: true,
+        params: example.params.clone(),
+        ..RunOptions::default()
+    };
+    let reports = (solution.run)(&example.input, &options);
+
+    for (part, expected) in (1..=2).zip(&example.expected) {
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let report = reports
+            .iter()
+            .find(|report| report.step == Step::Part(part))
+            .unwrap_or_else(|| panic!("Example \"{name}\": part {part} did not run."));
+
+        if let Some(panic) = &report.panic {
+            panic!("Example \"{name}\": part {part} panicked: {panic}");
+        }
+
+        assert_eq!(
+            report.answer.as_deref(),
+            Some(expected.as_str()),
+            "Example \"{name}\": part {part}"
+        );
+    }
+}
+
+/// Formats a manifest listing a single example that reads the day's example file.
+pub fn manifest(name: &str, expected: &[Option<String>; 2]) -> String {
+    let mut fields = vec![format!("\"name\": {}", to_json(name))];
+    for (part, expected) in (1..=2).zip(expected) {
+        if let Some(expected) = expected {
+            fields.push(format!("\"part_{part}\": {}", to_json(expected)));
+        }
+    }
+
+    format!(
+        "{{\n  \"examples\": [\n    {{ {} }}\n  ]\n}}\n",
+        fields.join(", ")
+    )
+}
+
+/// Formats a value as a JSON number if it can be read back exactly, as a string otherwise.
+fn to_json(value: &str) -> String {
+    match value.parse::<u64>() {
+        Ok(n) if n < 1 << 53 && n.to_string() == value => value.to_string(),
+        _ => JsonValue::String(value.to_string()).stringify().unwrap(),
+    }
+}
+
+/// Parses a manifest, reading the inputs of examples that refer to a file with `read_file`.
+fn parse_manifest(
+    manifest: &str,
+    default_file: &str,
+    read_file: impl Fn(&str) -> Result<String, String>,
+) -> Result<Vec<Example>, String> {
+    let json: JsonValue = manifest
+        .parse()
+        .map_err(|e| format!("Could not parse the examples: {e}"))?;
+
+    json.get::<HashMap<String, JsonValue>>()
+        .and_then(|json| json.get("examples"))
+        .and_then(|v| v.get::<Vec<JsonValue>>())
+        .ok_or("Expected examples to be an array.")?
+        .iter()
+        .map(|example| parse_example(example, default_file, &read_file))
+        .collect()
+}
+
+fn parse_example(
+    value: &JsonValue,
+    default_file: &str,
+    read_file: impl Fn(&str) -> Result<String, String>,
+) -> Result<Example, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected example to be a JSON object.")?;
+
+    let name = json
+        .get("name")
+        .and_then(|v| v.get::<String>())
+        .ok_or("Expected example.name to be a string.")?;
+
+    let input = match (json.get("input"), json.get("file")) {
+        (Some(input), _) => input
+            .get::<String>()
+            .cloned()
+            .ok_or(format!("Expected example \"{name}\".input to be a string."))?,
+        (None, Some(file)) => read_file(
+            file.get::<String>()
+                .ok_or(format!("Expected example \"{name}\".file to be a string."))?,
+        )?,
+        (None, None) => read_file(default_file)?,
+    };
+
+    let value = |key: &str| match json.get(key) {
+        None => Ok(None),
+        Some(v) => to_value(v).map(Some).ok_or(format!(
+            "Expected example \"{name}\".{key} to be a string or number."
+        )),
+    };
+    let expected = [value("part_1")?, value("part_2")?];
+
+    let mut params = match json.get("params") {
+        None => vec![],
+        Some(v) => v
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or(format!(
+                "Expected example \"{name}\".params to be a JSON object."
+            ))?
+            .iter()
+            .map(|(key, v)| {
+                to_value(v).map(|v| (key.clone(), v)).ok_or(format!(
+                    "Expected example \"{name}\".params.{key} to be a string or number."
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+    params.sort_unstable();
+
+    Ok(Example {
+        name: name.clone(),
+        input,
+        expected,
+        params,
+    })
+}
+
+/// Formats a string or a number as a value, e.g. `40` instead of `40.0`.
+fn to_value(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Number(n) if n.fract() == 0.0 => Some(format!("{n:.0}")),
+        JsonValue::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, manifest, parse_manifest};
+
+    fn read_file(file: &str) -> Result<String, String> {
+        match file {
+            "08.txt" => Ok("default\n".into()),
+            "08-2.txt" => Ok("second\n".into()),
+            _ => Err(format!("no {file}")),
+        }
+    }
+
+    #[test]
+    fn parses_manifests() {
+        let manifest = r#"{
+            "examples": [
+                { "name": "small", "part_1": 40, "part_2": "25272", "params": { "connections": 10, "mode": "fast" } },
+                { "name": "second", "file": "08-2.txt", "part_2": 3121910778619 },
+                { "name": "inline", "input": "1,2,3\n" }
+            ]
+        }"#;
+
+        let examples = parse_manifest(manifest, "08.txt", read_file).unwrap();
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    name: "small".into(),
+                    input: "default\n".into(),
+                    expected: [Some("40".into()), Some("25272".into())],
+                    params: vec![
+                        ("connections".into(), "10".into()),
+                        ("mode".into(), "fast".into())
+                    ],
+                },
+                Example {
+                    name: "second".into(),
+                    input: "second\n".into(),
+                    expected: [None, Some("3121910778619".into())],
+                    params: vec![],
+                },
+                Example {
+                    name: "inline".into(),
+                    input: "1,2,3\n".into(),
+                    expected: [None, None],
+                    params: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_manifests() {
+        let expected = [Some("40".into()), Some("ABC\"D".into())];
+        let manifest = manifest("example", &expected);
+        assert_eq!(
+            manifest,
+            "{\n  \"examples\": [\n    { \"name\": \"example\", \"part_1\": 40, \"part_2\": \"ABC\\\"D\" }\n  ]\n}\n"
+        );
+        assert_eq!(
+            parse_manifest(&manifest, "08.txt", read_file).unwrap()[0].expected,
+            expected
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        assert!(parse_manifest("[]", "08.txt", read_file).is_err());
+        let missing_file = r#"{ "examples": [{ "name": "a", "file": "09.txt" }] }"#;
+        assert_eq!(
+            parse_manifest(missing_file, "08.txt", read_file),
+            Err("no 09.txt".into())
+        );
+        let invalid_answer = r#"{ "examples": [{ "name": "a", "part_1": [] }] }"#;
+        assert!(parse_manifest(invalid_answer, "08.txt", read_file).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+/// Heap usage of solution parts, measured with the `dhat-heap` or the `count-alloc` feature.
+use std::{collections::HashMap, fmt::Display};
+
+use tinyjson::JsonValue;
+
+/// Heap usage of a single run of a part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Maximum number of bytes allocated at the same time.
+    pub peak_bytes: u64,
+    pub total_allocations: u64,
+    pub total_bytes: u64,
+}
+
+impl MemoryStats {
+    /// Runs `func` and measures its heap usage with the dhat profiler or the counting allocator.
+    /// The usage is `None` if neither `dhat-heap` nor `count-alloc` is enabled.
+    pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<Self>) {
+        #[cfg(feature = "dhat-heap")]
+        {
+            let _profiler = dhat::Profiler::new_heap();
+            let result = func();
+            let stats = dhat::HeapStats::get();
+            let memory = Self {
+                peak_bytes: stats.max_bytes as u64,
+                total_allocations: stats.total_blocks,
+                total_bytes: stats.total_bytes,
+            };
+            (result, Some(memory))
+        }
+
+        #[cfg(all(feature = "count-alloc", not(feature = "dhat-heap")))]
+        {
+            let snapshot = crate::template::counting_alloc::Snapshot::start();
+            let result = func();
+            (result, Some(snapshot.stop()))
+        }
+
+        #[cfg(not(any(feature = "dhat-heap", feature = "count-alloc")))]
+        (func(), None)
+    }
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocations ({})",
+            format_bytes(self.peak_bytes),
+            self.total_allocations,
+            format_bytes(self.total_bytes)
+        )
+    }
+}
+
+/// Formats a number of bytes with a binary prefix, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&MemoryStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &MemoryStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+        map.insert(
+            "total_allocations".into(),
+            JsonValue::Number(value.total_allocations as f64),
+        );
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|&x| x as u64)
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        Ok(MemoryStats {
+            peak_bytes: number("peak_bytes")?,
+            total_allocations: number("total_allocations")?,
+            total_bytes: number("total_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{MemoryStats, format_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn round_trips_memory() {
+        let memory = MemoryStats {
+            peak_bytes: 2048,
+            total_allocations: 12,
+            total_bytes: 4096,
+        };
+
+        assert_eq!(memory.to_string(), "peak 2.0 KiB, 12 allocations (4.0 KiB)");
+        assert_eq!(
+            MemoryStats::try_from(&JsonValue::from(&memory)).unwrap(),
+            memory
+        );
+    }
+}
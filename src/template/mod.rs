@@ -1,68 +1,327 @@
-use std::{env, fs};
+use std::fs;
+use std::path::{Path, PathBuf};
 
 pub mod aoc_cli;
+#[cfg(feature = "http")]
+pub mod aoc_client;
+pub mod cli;
 pub mod commands;
+pub mod counting_alloc;
+pub mod description;
+pub mod examples;
+pub mod ocr;
+pub mod registry;
 pub mod runner;
 
+pub use answer::{Answer, LetterGrid};
+#[doc(hidden)]
+pub use answer::{AnswerType, UncheckedAnswerType};
+pub use answers::{AnswerCheck, Answers, Refusal, Verdict};
+pub use bench::{BenchConfig, BenchStats};
+pub use context::{Context, Params};
 pub use day::*;
+pub use memory::MemoryStats;
+pub use panic::Panic;
+pub use puzzle::*;
+pub use report::{OutputFormat, PartReport, Step};
+pub use year::*;
 
+mod answer;
+mod answers;
+mod bench;
+mod compare;
+mod context;
 mod day;
+mod memory;
+mod panic;
+mod puzzle;
 mod readme_benchmarks;
+mod report;
 mod run_multi;
+mod status;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the path of a data file in the per-year layout, e.g. `data/2025/inputs/01.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, year: Year, file_name: &str) -> PathBuf {
+    Path::new("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(file_name)
+}
+
+/// Resolves a data file, falling back to the legacy flat layout (`data/<folder>/<file>`)
+/// for the configured year as long as it has not been migrated.
+pub(crate) fn resolve_data_path(folder: &str, year: Year, file_name: &str) -> PathBuf {
+    let path = get_data_path(folder, year, file_name);
+    let legacy_path = Path::new("data").join(folder).join(file_name);
+
+    if !path.exists() && year == Year::current() && legacy_path.exists() {
+        legacy_path
+    } else {
+        path
+    }
+}
+
+/// Returns the name of the binary that solves a puzzle, e.g. `2025_01`.
+/// Legacy binaries of the configured year (e.g. `01`) are used until they are migrated.
+#[must_use]
+pub fn get_bin_name(puzzle: PuzzleId) -> String {
+    let name = format!("{}_{}", puzzle.year, puzzle.day);
+    let legacy_name = puzzle.day.to_string();
+
+    if !Path::new(&bin_path(&name)).exists()
+        && puzzle.year == Year::current()
+        && Path::new(&bin_path(&legacy_name)).exists()
+    {
+        legacy_name
+    } else {
+        name
+    }
+}
+
+#[must_use]
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    bin_path(&get_bin_name(puzzle))
+}
+
+fn bin_path(name: &str) -> String {
+    format!("./src/bin/{name}.rs")
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+pub fn read_file(folder: &str, puzzle: impl Into<PuzzleId>) -> String {
+    let puzzle = puzzle.into();
+    let filepath = resolve_data_path(folder, puzzle.year, &format!("{}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
+pub fn read_file_part(folder: &str, puzzle: impl Into<PuzzleId>, part: u8) -> String {
+    let puzzle = puzzle.into();
+    let filepath = resolve_data_path(folder, puzzle.year, &format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY`, `PUZZLE` and `SOLUTION` and sets up the input and runner for each part.
+///
+/// The year can be given explicitly with `solution!(year = 2024, 5)`, otherwise `AOC_YEAR` is
+/// used at compile time.
+///
+/// With `solution!(5, parse = parse)`, the input is parsed once by `parse(&str) -> Input` and both
+/// parts take the parsed input as `part_one(&Input)`. Parsing is timed separately.
+///
+/// With `solution!(7, solve = solve)`, `solve(&str) -> (Option<A>, Option<B>)` returns the answers
+/// of both parts at once and is timed as a whole.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// With `solution!(8, params = { connections: 1000 }, example_params = { connections: 10 })`, the
+/// parts (or `solve`) take a [`Context`] as well, e.g. `part_one(&str, &Context)`, which tells
+/// whether the input is an example and holds the parameters for that kind of input. The
+/// parameters are also available as the constant `PARAMS`. Both parameter sets may be empty.
+///
+/// The optional, last parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Parts that return a signed integer get a deprecation warning, negative answers are never
+/// accepted. With `solution!(9, signed)`, right after the day, the parts may return signed answers.
+///
+/// Every example listed in the manifest of the day gets a test, see [`crate::template::examples`].
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    (year = $year:expr, $day:expr, signed $(, $($rest:tt)+)?) => {
+        $crate::solution!(@params $crate::year!($year), $day, [signed]; $($($rest)+)?);
+    };
+    (year = $year:expr, $day:expr $(, $($rest:tt)+)?) => {
+        $crate::solution!(@params $crate::year!($year), $day, [checked]; $($($rest)+)?);
+    };
+    ($day:expr, signed $(, $($rest:tt)+)?) => {
+        $crate::solution!(@params $crate::solution!(@env_year), $day, [signed]; $($($rest)+)?);
+    };
+    ($day:expr $(, $($rest:tt)+)?) => {
+        $crate::solution!(@params $crate::solution!(@env_year), $day, [checked]; $($($rest)+)?);
+    };
+
+    (@env_year) => {
+        $crate::template::Year::__from_env(option_env!("AOC_YEAR"))
+    };
+
+    (@params $year:expr, $day:expr, $check:tt;
+        params = { $($name:ident : $value:expr),* $(,)? },
+        example_params = { $($example_name:ident : $example_value:expr),* $(,)? }
+        $(, $($rest:tt)+)?
+    ) => {
+        /// Parameters of this solution for the real input and for examples.
+        pub const PARAMS: $crate::template::Params = $crate::template::Params {
+            real: &[$((stringify!($name), &$value)),*],
+            example: &[$((stringify!($example_name), &$example_value)),*],
+        };
+
+        $crate::solution!(@parts $year, $day, [context], $check; $($($rest)+)?);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    (@params $year:expr, $day:expr, $check:tt;
+        params = { $($name:ident : $value:expr),* $(,)? }
+        $(, $($rest:tt)+)?
+    ) => {
+        $crate::solution!(@params $year, $day, $check;
+            params = { $($name: $value),* }, example_params = {} $(, $($rest)+)?);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@params $year:expr, $day:expr, $check:tt; $($rest:tt)*) => {
+        $crate::solution!(@parts $year, $day, [plain], $check; $($rest)*);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@parts $year:expr, $day:expr, $kind:tt, $check:tt; ) => {
+        $crate::solution!(@impl $year, $day, $kind, $check, [part_one, 1] [part_two, 2]);
+    };
+    (@parts $year:expr, $day:expr, $kind:tt, $check:tt; 1) => {
+        $crate::solution!(@impl $year, $day, $kind, $check, [part_one, 1]);
+    };
+    (@parts $year:expr, $day:expr, $kind:tt, $check:tt; 2) => {
+        $crate::solution!(@impl $year, $day, $kind, $check, [part_two, 2]);
+    };
+    (@parts $year:expr, $day:expr, $kind:tt, $check:tt; parse = $parse:expr) => {
+        $crate::solution!(@parsed $year, $day, $kind, $check, $parse, [part_one, 1] [part_two, 2]);
+    };
+    (@parts $year:expr, $day:expr, $kind:tt, $check:tt; parse = $parse:expr, 1) => {
+        $crate::solution!(@parsed $year, $day, $kind, $check, $parse, [part_one, 1]);
+    };
+    (@parts $year:expr, $day:expr, $kind:tt, $check:tt; parse = $parse:expr, 2) => {
+        $crate::solution!(@parsed $year, $day, $kind, $check, $parse, [part_two, 2]);
+    };
+
+    (@parts $year:expr, $day:expr, $kind:tt, $check:tt; solve = $solve:expr) => {
+        $crate::solution!(@define $year, $day, |input, options| {
+            let _context = $crate::solution!(@context $kind, options);
+            $crate::template::runner::run_both(
+                |input| {
+                    let (part_1, part_2) = $crate::solution!(@call $kind, $solve, input, &_context);
+                    (
+                        $crate::solution!(@checked $check, part_1),
+                        $crate::solution!(@checked $check, part_2),
+                    )
+                },
+                input,
+                PUZZLE,
+                options,
+            )
+        });
+    };
+
+    (@impl $year:expr, $day:expr, $kind:tt, $check:tt, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@define $year, $day, |input, options| {
+            use $crate::template::runner::run_part;
+            let _context = $crate::solution!(@context $kind, options);
+            vec![$(
+                run_part(
+                    |input| $crate::solution!(@checked $check,
+                        $crate::solution!(@call $kind, $func, input, &_context)),
+                    input,
+                    PUZZLE,
+                    $part,
+                    options,
+                ),
+            )*]
+        });
+    };
+
+    (@parsed $year:expr, $day:expr, $kind:tt, $check:tt, $parse:expr,
+        $( [$func:expr, $part:expr] )*
+    ) => {
+        $crate::solution!(@define $year, $day, |input, options| {
+            use $crate::template::runner::{run_parse, run_part};
+            let _context = $crate::solution!(@context $kind, options);
+            let (parsed, report) = run_parse($parse, input, PUZZLE, options);
+            let mut reports = vec![report];
+            // the parts can not run without their input if parsing panicked.
+            if let Some(parsed) = parsed {
+                $( reports.push(run_part(
+                    |parsed| $crate::solution!(@checked $check,
+                        $crate::solution!(@call $kind, $func, parsed, &_context)),
+                    &parsed,
+                    PUZZLE,
+                    $part,
+                    options,
+                )); )*
+            }
+            reports
+        });
+    };
+
+    (@context [plain], $options:expr) => {
+        ()
+    };
+    (@context [context], $options:expr) => {
+        $crate::template::Context::new(&PARAMS, $options)
+    };
+
+    (@call [plain], $func:expr, $input:expr, $context:expr) => {
+        $func($input)
+    };
+    (@call [context], $func:expr, $input:expr, $context:expr) => {
+        $func($input, $context)
+    };
+
+    // warns at compile time about signed answers, see `AnswerType`.
+    (@checked [signed], $answer:expr) => {
+        $answer
+    };
+    (@checked [checked], $answer:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::template::UncheckedAnswerType as _;
+        let answer = $answer;
+        $crate::template::AnswerType::of(&answer).check();
+        answer
+    }};
+
+    (@define $year:expr, $day:expr, $run:expr) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The puzzle solved by this binary.
+        const PUZZLE: $crate::template::PuzzleId = $crate::template::PuzzleId::new($year, DAY);
+
+        /// Runs this solution, also called in-process by the `all` binary.
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution::new(PUZZLE, $run);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(all(feature = "count-alloc", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::counting_alloc::CountingAlloc =
+            $crate::template::counting_alloc::CountingAlloc;
+
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::read_file("inputs", PUZZLE);
+            $crate::template::runner::run_solution(&SOLUTION, &input);
+        }
+
+        $crate::example_tests!();
+    };
+}
+
+/// Includes a test for every example listed in the manifest of the current solution, which are
+/// generated by `build.rs`. Called by `solution!`, see [`crate::template::examples`].
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod example_tests {
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/examples/",
+                env!("CARGO_BIN_NAME"),
+                ".rs"
+            ));
         }
     };
 }
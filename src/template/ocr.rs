@@ -0,0 +1,163 @@
+/// Letters that are 4 cells wide and 6 cells high, e.g. drawn in 2016 day 8 and 2022 day 10.
+const SMALL_FONT: (&str, [&str; 6]) = (
+    "ABCEFGHIJKLOPRSUYZ",
+    [
+        ".##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.#...#.####",
+        "#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#",
+        "#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.",
+        "####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#...#....#..",
+        "#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...",
+        "#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##....#...####",
+    ],
+);
+
+/// Letters that are 6 cells wide and 10 cells high, e.g. drawn in 2018 day 10.
+const LARGE_FONT: (&str, [&str; 10]) = (
+    "ABCEFGHJKLNPRXZ",
+    [
+        "..##...#####...####..######.######..####..#....#....###.#....#.#......#....#.#####..#####..#....#.######",
+        ".#..#..#....#.#....#.#......#......#....#.#....#.....#..#...#..#......##...#.#....#.#....#.#....#......#",
+        "#....#.#....#.#......#......#......#......#....#.....#..#..#...#......##...#.#....#.#....#..#..#.......#",
+        "#....#.#....#.#......#......#......#......#....#.....#..#.#....#......#.#..#.#....#.#....#..#..#......#.",
+        "#....#.#####..#......#####..#####..#......######.....#..##.....#......#.#..#.#####..#####....##......#..",
+        "######.#....#.#......#......#......#..###.#....#.....#..##.....#......#..#.#.#......#..#.....##.....#...",
+        "#....#.#....#.#......#......#......#....#.#....#.....#..#.#....#......#..#.#.#......#...#...#..#...#....",
+        "#....#.#....#.#......#......#......#....#.#....#.#...#..#..#...#......#...##.#......#...#...#..#..#.....",
+        "#....#.#....#.#....#.#......#......#...##.#....#.#...#..#...#..#......#...##.#......#....#.#....#.#.....",
+        "#....#.#####...####..######.#.......###.#.#....#..###...#....#.######.#....#.#......#....#.#....#.######",
+    ],
+);
+
+/// Cells of a grid, `true` for lit cells.
+type Cells = Vec<Vec<bool>>;
+
+/// Reads the block letters drawn on a grid, with `#` or `█` for lit cells and anything else
+/// for dark ones. Blank rows around the letters are ignored, the letters must be separated by
+/// at least one blank column. Returns `None` if any letter is not recognized.
+pub fn recognize(grid: &str) -> Option<String> {
+    let mut rows: Cells = grid
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+
+    let is_blank_row = |row: &Vec<bool>| !row.contains(&true);
+    while rows.first().is_some_and(is_blank_row) {
+        rows.remove(0);
+    }
+    while rows.last().is_some_and(is_blank_row) {
+        rows.pop();
+    }
+
+    let (alphabet, glyphs): (&str, &[&str]) = match rows.len() {
+        6 => (SMALL_FONT.0, &SMALL_FONT.1),
+        10 => (LARGE_FONT.0, &LARGE_FONT.1),
+        _ => return None,
+    };
+    let font: Vec<(char, Cells)> = alphabet
+        .chars()
+        .zip(split_letters(&parse(glyphs)))
+        .collect();
+
+    let letters: String = split_letters(&rows)
+        .iter()
+        .map(|cells| {
+            font.iter()
+                .find(|(_, glyph)| glyph == cells)
+                .map(|(letter, _)| *letter)
+        })
+        .collect::<Option<_>>()?;
+
+    (!letters.is_empty()).then_some(letters)
+}
+
+/// Parses the rows of a font, `#` for lit cells.
+fn parse(rows: &[&str]) -> Cells {
+    rows.iter()
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect()
+}
+
+/// Splits a grid into the cells of its letters at blank columns.
+fn split_letters(rows: &Cells) -> Vec<Cells> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let is_lit = |row: &Vec<bool>, x: usize| row.get(x).copied().unwrap_or_default();
+    let is_blank_column = |x: usize| rows.iter().all(|row| !is_lit(row, x));
+
+    let mut letters = vec![];
+    let mut x = 0;
+
+    while x < width {
+        if is_blank_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !is_blank_column(x) {
+            x += 1;
+        }
+        letters.push(
+            rows.iter()
+                .map(|row| (start..x).map(|x| is_lit(row, x)).collect())
+                .collect(),
+        );
+    }
+
+    letters
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{LARGE_FONT, SMALL_FONT, parse, recognize, split_letters};
+
+    #[test]
+    fn splits_fonts() {
+        for (alphabet, glyphs) in [
+            (SMALL_FONT.0, &SMALL_FONT.1[..]),
+            (LARGE_FONT.0, &LARGE_FONT.1[..]),
+        ] {
+            assert_eq!(split_letters(&parse(glyphs)).len(), alphabet.len());
+            assert_eq!(recognize(&glyphs.join("\n")).as_deref(), Some(alphabet));
+        }
+    }
+
+    #[test]
+    fn recognizes_small_letters() {
+        let grid = "\
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.";
+        assert_eq!(recognize(grid), Some("HELLO".into()));
+    }
+
+    #[test]
+    fn recognizes_large_letters() {
+        let grid = "
+#....#..######
+#....#..#.....
+.#..#...#.....
+.#..#...#.....
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..#.....
+";
+        assert_eq!(recognize(grid), Some("XF".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_letters() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize("....\n....\n....\n....\n....\n...."), None);
+
+        let grid = "#...#\n##.##\n#.#.#\n#...#\n#...#\n#...#";
+        assert_eq!(recognize(grid), None);
+    }
+}
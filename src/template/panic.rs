@@ -0,0 +1,186 @@
+/// Panics of solution steps, caught so that a failing part does not stop the other parts.
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe, Location};
+use std::path::Path;
+use std::sync::Once;
+
+use tinyjson::JsonValue;
+
+thread_local! {
+    /// Whether the current thread runs [`Panic::catch`], its panics are recorded instead of printed.
+    static IS_CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The last panic of the current thread, recorded by the hook installed in [`Panic::catch`].
+    static LAST_PANIC: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// A panic of a step, e.g. an `unwrap` on `None` or an `unreachable!()` that was reached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Source location of the panic, e.g. `src/bin/10.rs:42:5`.
+    pub location: Option<String>,
+}
+
+impl Panic {
+    /// Runs `func` and catches an unwinding panic instead of letting it stop the process.
+    /// The panic is not printed by the default hook, the caller reports it instead.
+    pub fn catch<T>(func: impl FnOnce() -> T) -> Result<T, Self> {
+        // the hook is shared by all threads, e.g. tests running in parallel, so it is installed
+        // once and only records the panics of threads that are catching them.
+        INSTALL_HOOK.call_once(|| {
+            let default_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !IS_CATCHING.get() {
+                    default_hook(info);
+                    return;
+                }
+
+                let panic = Panic {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(format_location),
+                };
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(panic));
+            }));
+        });
+
+        let was_catching = IS_CATCHING.replace(true);
+        let result = panic::catch_unwind(AssertUnwindSafe(func));
+        IS_CATCHING.set(was_catching);
+
+        result.map_err(|payload| {
+            // panics of other threads that are resumed on this one were not recorded here.
+            LAST_PANIC
+                .with(|last| last.borrow_mut().take())
+                .unwrap_or_else(|| Panic {
+                    message: payload_message(payload.as_ref()),
+                    location: None,
+                })
+        })
+    }
+}
+
+/// Formats a source location relative to the working directory, the solutions compiled into
+/// the `all` binary are included with their absolute path.
+fn format_location(location: &Location) -> String {
+    let file = Path::new(location.file());
+    let file = env::current_dir()
+        .ok()
+        .and_then(|dir| file.strip_prefix(dir).ok())
+        .unwrap_or(file);
+    format!(
+        "{}:{}:{}",
+        file.display(),
+        location.line(),
+        location.column()
+    )
+}
+
+/// Returns the message of a panic payload, which is a `&str` or a `String` for `panic!` and friends.
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).into()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".into()
+    }
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} at {location}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Panic> for JsonValue {
+    fn from(value: &Panic) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("message".into(), JsonValue::String(value.message.clone()));
+        map.insert(
+            "location".into(),
+            match &value.location {
+                Some(location) => JsonValue::String(location.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Panic {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected panic to be a JSON object.")?;
+
+        let message = json
+            .get("message")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected panic.message to be a string.")?;
+
+        let location = json
+            .get("location")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected panic.location to be null or string.")?;
+
+        Ok(Panic {
+            message: message.clone(),
+            location: location.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::Panic;
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(Panic::catch(|| 42), Ok(42));
+
+        let panic = Panic::catch(|| -> u32 { unreachable!("no path to {}", "out") }).unwrap_err();
+        assert_eq!(
+            panic.message,
+            "internal error: entered unreachable code: no path to out"
+        );
+        assert!(
+            panic
+                .location
+                .unwrap()
+                .starts_with("src/template/panic.rs:")
+        );
+
+        let panic = Panic::catch(|| None::<u32>.expect("no input")).unwrap_err();
+        assert_eq!(panic.message, "no input");
+    }
+
+    #[test]
+    fn round_trips_panics() {
+        let panic = Panic {
+            message: "no input".into(),
+            location: Some("src/bin/10.rs:42:5".into()),
+        };
+
+        assert_eq!(panic.to_string(), "no input at src/bin/10.rs:42:5");
+        assert_eq!(Panic::try_from(&JsonValue::from(&panic)).unwrap(), panic);
+    }
+}
@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a single puzzle by its [`Year`] and [`Day`].
+///
+/// # Display
+/// This value displays as `<year>/<day>`.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2024).unwrap(), Day::new(5).unwrap());
+/// assert_eq!(puzzle.to_string(), "2024/05")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates a [`PuzzleId`] if the day is part of the year's calendar (see [`Year::day_count`]),
+    /// returns [`None`] otherwise.
+    pub fn try_new(year: Year, day: Day) -> Option<Self> {
+        year.has_day(day).then_some(Self::new(year, day))
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns today's puzzle if it's a day of advent in december, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() != 12 {
+            return None;
+        }
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        let day = Day::new(u8::try_from(today.day()).ok()?)?;
+        Self::try_new(year, day)
+    }
+}
+
+/// Resolves a bare [`Day`] against the configured [`Year::current`].
+impl From<Day> for PuzzleId {
+    fn from(day: Day) -> Self {
+        Self::new(Year::current(), day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses either `<year>/<day>` or a bare `<day>` of the configured year.
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((year, day)) => {
+                let year = year.parse().map_err(|_| PuzzleIdFromStrError)?;
+                let day = day.parse().ok().and_then(Day::new);
+                day.and_then(|day| Self::try_new(year, day))
+                    .ok_or(PuzzleIdFromStrError)
+            }
+            None => {
+                let day = s.parse::<Day>().map_err(|_| PuzzleIdFromStrError)?;
+                Self::try_new(Year::current(), day).ok_or(PuzzleIdFromStrError)
+            }
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "expecting a puzzle of the calendar as `<day>` or `<year>/<day>`, e.g. `2024/05`",
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        $crate::template::PuzzleId::new($crate::year!($year), $crate::day!($day))
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, template::Year};
+
+    #[test]
+    fn parses_qualified_puzzles() {
+        let puzzle: PuzzleId = "2024/05".parse().unwrap();
+        assert_eq!(puzzle, puzzle!(2024, 5));
+        assert_eq!(puzzle.to_string(), "2024/05");
+
+        let puzzle: PuzzleId = "2024/20".parse().unwrap();
+        assert_eq!(puzzle, puzzle!(2024, 20));
+    }
+
+    #[test]
+    fn parses_bare_days() {
+        let puzzle: PuzzleId = "7".parse().unwrap();
+        assert_eq!(puzzle, PuzzleId::new(Year::current(), day!(7)));
+    }
+
+    #[test]
+    fn rejects_invalid_puzzles() {
+        assert!("2024/26".parse::<PuzzleId>().is_err());
+        assert!("2025/13".parse::<PuzzleId>().is_err());
+        assert!("1999/01".parse::<PuzzleId>().is_err());
+        assert!("2024-01".parse::<PuzzleId>().is_err());
+        assert!("26".parse::<PuzzleId>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -2,8 +2,10 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::memory::format_bytes;
+use crate::template::timings::Timing;
 use crate::template::timings::Timings;
+use crate::template::{BenchStats, Step, get_path_for_bin};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,52 +27,101 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates a generated section of the readme, which starts and ends with `marker`.
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
         .first()
         .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("Could not find start position of {marker}.")))?;
 
     let pos_end = matches
         .last()
         .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("Could not find end position of {marker}.")))?;
 
     Ok(TablePosition { pos_start, pos_end })
 }
 
+/// Replaces a section located by [`locate_table`] with `content`.
+pub(crate) fn replace_table(readme: &mut String, marker: &str, content: &str) -> Result<(), Error> {
+    let positions = locate_table(readme, marker)?;
+    readme.replace_range(positions.pos_start..positions.pos_end, content);
+    Ok(())
+}
+
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // only add memory columns once `cargo time --memory` stored heap usage.
+    let has_memory = timings.data.iter().any(|t| !t.memory.is_empty());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_memory {
+        lines.push("| Day | Parse | Part 1 | Part 2 | Peak heap | Allocations |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
+
+    // only qualify days with their year if the table spans several years.
+    let is_multi_year = timings
+        .data
+        .windows(2)
+        .any(|w| w[0].puzzle.year != w[1].puzzle.year);
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(timing.puzzle);
+        let label = if is_multi_year {
+            format!(
+                "{} Day {}",
+                timing.puzzle.year,
+                timing.puzzle.day.into_inner()
+            )
+        } else {
+            format!("Day {}", timing.puzzle.day.into_inner())
+        };
+        let memory = if has_memory {
+            format_memory(&timing)
+        } else {
+            String::new()
+        };
+        let cell = |step: Step, stats: &Option<BenchStats>| match (timing.timeout(step), stats) {
+            (Some(timeout), _) => format!("timed out after {timeout:.1?}"),
+            (None, Some(stats)) => format!("`{:.1?}`", stats.median),
+            (None, None) => "`-`".into(),
+        };
+        // parts solved at once share a single timing.
+        let is_combined = timing.combined.is_some() || timing.timeout(Step::Both).is_some();
+        let (part_1, part_2) = if is_combined && timing.part_1.is_none() && timing.part_2.is_none()
+        {
+            (
+                format!("{} (both parts)", cell(Step::Both, &timing.combined)),
+                "(with part 1)".into(),
+            )
+        } else {
+            (
+                cell(Step::Part(1), &timing.part_1),
+                cell(Step::Part(2), &timing.part_2),
+            )
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [{}]({}) | {} | {} | {} |{}",
+            label,
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            cell(Step::Parse, &timing.parse),
+            part_1,
+            part_2,
+            memory
         ));
     }
 
@@ -81,11 +132,26 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
+/// Formats the peak heap usage and the allocations summed over all steps of a day.
+fn format_memory(timing: &Timing) -> String {
+    if timing.memory.is_empty() {
+        return " `-` | `-` |".into();
+    }
+
+    let peak = timing.memory.iter().map(|(_, m)| m.peak_bytes).max();
+    let allocations: u64 = timing.memory.iter().map(|(_, m)| m.total_allocations).sum();
+    let bytes: u64 = timing.memory.iter().map(|(_, m)| m.total_bytes).sum();
+
+    format!(
+        " `{}` | `{allocations}` ({}) |",
+        format_bytes(peak.unwrap_or_default()),
+        format_bytes(bytes)
+    )
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+    replace_table(s, MARKER, &table)
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
@@ -100,30 +166,47 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use std::time::Duration;
+
+    use crate::template::Step;
+    use crate::template::timings::tests::{memory, stats};
+    use crate::{puzzle, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
+                    part_1: Some(stats(10)),
+                    part_2: Some(stats(20)),
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    puzzle: puzzle!(2025, 2),
+                    parse: None,
+                    part_1: Some(stats(30)),
+                    part_2: Some(stats(40)),
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    puzzle: puzzle!(2025, 4),
+                    parse: Some(stats(5)),
+                    part_1: Some(stats(40)),
+                    part_2: Some(stats(50)),
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 9e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -157,6 +240,53 @@ mod tests {
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn qualifies_days_spanning_several_years() {
+        let mut timings = get_mock_timings();
+        timings.data[0].puzzle = puzzle!(2024, 1);
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [2024 Day 1](./src/bin/2024_01.rs) |"));
+        assert!(s.contains("| [2025 Day 2]("));
+    }
+
+    #[test]
+    fn formats_combined_parts() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_1 = None;
+        timings.data[1].part_2 = None;
+        timings.data[1].combined = Some(stats(25));
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains(
+            "| [Day 2](./src/bin/02.rs) | `-` | `25.0ms` (both parts) | (with part 1) |"
+        ));
+    }
+
+    #[test]
+    fn formats_memory_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[0].memory = vec![
+            (Step::Part(1), memory(2048, 3, 4096)),
+            (Step::Part(2), memory(1024, 2, 1024)),
+        ];
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 | Peak heap | Allocations |"));
+        assert!(s.contains("| `10.0ms` | `20.0ms` | `2.0 KiB` | `5` (5.0 KiB) |"));
+        assert!(s.contains("| `30.0ms` | `40.0ms` | `-` | `-` |"));
+    }
+
+    #[test]
+    fn formats_timeouts() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_2 = None;
+        timings.data[1].timeouts = vec![(Step::Part(2), Duration::from_secs(5))];
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `-` | `30.0ms` | timed out after 5.0s |"));
+    }
+
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
@@ -167,11 +297,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `-` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `5.0ms` | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -0,0 +1,55 @@
+/// Solutions that can be called in-process, without spawning a binary per day.
+///
+/// Every `solution!` invocation defines a `SOLUTION` constant. The `all` binary compiles each day
+/// as one of its modules and installs their constants here before running `all` or `time`.
+use std::sync::OnceLock;
+
+use crate::template::runner::RunOptions;
+use crate::template::{PartReport, PuzzleId};
+
+/// Runs every step of a solution (parsing and parts) on an input and returns their reports.
+pub type RunFn = fn(&str, &RunOptions) -> Vec<PartReport>;
+
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub run: RunFn,
+}
+
+impl Solution {
+    pub const fn new(puzzle: PuzzleId, run: RunFn) -> Self {
+        Self { puzzle, run }
+    }
+}
+
+static REGISTRY: OnceLock<Vec<Solution>> = OnceLock::new();
+
+/// Installs the solutions compiled into the current binary. Only the first call has an effect.
+pub fn install(solutions: Vec<Solution>) {
+    let _ = REGISTRY.set(solutions);
+}
+
+/// Returns the installed solution of a puzzle. The first solution wins if several are installed.
+pub fn find(puzzle: PuzzleId) -> Option<&'static Solution> {
+    REGISTRY.get()?.iter().find(|s| s.puzzle == puzzle)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Solution;
+    use crate::puzzle;
+    use crate::template::runner::RunOptions;
+
+    #[test]
+    fn runs_solution() {
+        let solution = Solution::new(puzzle!(2025, 1), |input, _| {
+            assert_eq!(input, "42");
+            vec![]
+        });
+
+        assert_eq!(solution.puzzle, puzzle!(2025, 1));
+        assert!((solution.run)("42", &RunOptions::default()).is_empty());
+    }
+}
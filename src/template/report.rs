@@ -0,0 +1,517 @@
+use std::{
+    collections::HashMap, env, error::Error, fmt::Display, process, str::FromStr, time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{AnswerCheck, BenchStats, Day, MemoryStats, Panic, PuzzleId, Year};
+
+/// How results are printed by `solve`, `all` and `time`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output.
+    #[default]
+    Text,
+    /// A single JSON document with all parts, printed once everything ran.
+    Json,
+    /// One JSON object per part, printed as soon as the part ran.
+    JsonLines,
+}
+
+impl OutputFormat {
+    /// Reads the `--format` argument of the current process, defaulting to [`OutputFormat::Text`].
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let Some(index) = args.iter().position(|x| x == "--format") else {
+            return Self::default();
+        };
+
+        match args.get(index + 1).map(|x| x.parse()) {
+            Some(Ok(format)) => format,
+            Some(Err(e)) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("Unexpected command-line input. Format: --format <text|json|jsonl>");
+                process::exit(1);
+            }
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::JsonLines => "jsonl",
+        }
+    }
+
+    pub fn is_text(self) -> bool {
+        self == OutputFormat::Text
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "jsonl" => Ok(OutputFormat::JsonLines),
+            _ => Err(OutputFormatFromStrError(s.to_string())),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError(String);
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown format \"{}\", expecting text, json or jsonl",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// What a report measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// Parsing the input shared by both parts, see `solution!(…, parse = …)`.
+    Parse,
+    /// Solving a part (1 or 2).
+    Part(u8),
+    /// Solving both parts at once, see `solution!(…, solve = …)`.
+    Both,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "Parse"),
+            Step::Part(part) => write!(f, "Part {part}"),
+            Step::Both => write!(f, "Both parts"),
+        }
+    }
+}
+
+/// Result of running a single step of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub puzzle: PuzzleId,
+    pub step: Step,
+    pub answer: Option<String>,
+    pub stats: BenchStats,
+    /// Heap usage of the first run, only measured with the `dhat-heap` feature.
+    pub memory: Option<MemoryStats>,
+    pub check: AnswerCheck,
+    /// Set if the step was aborted because it ran longer than this.
+    pub timeout: Option<Duration>,
+    /// Set if the step panicked, its answer is `None` then.
+    pub panic: Option<Panic>,
+    /// Why the answer is most likely wrong, see [`crate::template::Answer::warning`].
+    pub warning: Option<String>,
+}
+
+impl PartReport {
+    /// Returns `pass` or `fail` for parts with an accepted answer, `solved` or `unsolved` otherwise.
+    /// Parsing reports `parsed`, solving both parts at once `combined`, aborted steps `timeout`
+    /// and steps that panicked `panicked`.
+    pub fn status(&self) -> &'static str {
+        if self.timeout.is_some() {
+            return "timeout";
+        }
+
+        if self.panic.is_some() {
+            return "panicked";
+        }
+
+        match self.step {
+            Step::Parse => return "parsed",
+            Step::Both => return "combined",
+            Step::Part(_) => {}
+        }
+
+        match (&self.check, &self.answer) {
+            (AnswerCheck::Pass, _) => "pass",
+            (AnswerCheck::Fail { .. }, _) => "fail",
+            (AnswerCheck::Unknown, Some(_)) => "solved",
+            (AnswerCheck::Unknown, None) => "unsolved",
+        }
+    }
+
+    /// Formats the report as a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self).stringify().unwrap()
+    }
+
+    /// An unchecked report without answer, for tests to fill in the fields they care about.
+    #[cfg(feature = "test_lib")]
+    pub(crate) fn empty(puzzle: PuzzleId, step: Step) -> Self {
+        PartReport {
+            puzzle,
+            step,
+            answer: None,
+            stats: BenchStats::from_duration(Duration::ZERO, 0),
+            memory: None,
+            check: AnswerCheck::Unknown,
+            timeout: None,
+            panic: None,
+            warning: None,
+        }
+    }
+}
+
+/// Formats a set of reports as a JSON document, e.g. `{"data": [...]}`.
+pub fn to_json_document(reports: &[PartReport]) -> String {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert(
+        "data".into(),
+        JsonValue::Array(reports.iter().map(JsonValue::from).collect()),
+    );
+    JsonValue::Object(map).format().unwrap()
+}
+
+impl FromStr for PartReport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON."))?;
+        PartReport::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+        let (step, part) = match value.step {
+            Step::Parse => ("parse", JsonValue::Null),
+            Step::Part(part) => ("part", JsonValue::Number(f64::from(part))),
+            Step::Both => ("both", JsonValue::Null),
+        };
+        map.insert("step".into(), JsonValue::String(step.into()));
+        map.insert("part".into(), part);
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(answer) => JsonValue::String(answer.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.stats.median.as_nanos() as f64),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+        map.insert(
+            "memory".into(),
+            match &value.memory {
+                Some(memory) => JsonValue::from(memory),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("status".into(), JsonValue::String(value.status().into()));
+
+        if let AnswerCheck::Fail { expected } = &value.check {
+            map.insert("expected".into(), JsonValue::String(expected.clone()));
+        }
+
+        if let Some(timeout) = value.timeout {
+            map.insert(
+                "timeout_secs".into(),
+                JsonValue::Number(timeout.as_secs_f64()),
+            );
+        }
+
+        if let Some(panic) = &value.panic {
+            map.insert("panic".into(), JsonValue::from(panic));
+        }
+
+        if let Some(warning) = &value.warning {
+            map.insert("warning".into(), JsonValue::String(warning.clone()));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected report.year to be a Year struct.")?;
+
+        let puzzle = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| day.parse().ok())
+            .and_then(Day::new)
+            .and_then(|day| PuzzleId::try_new(year, day))
+            .ok_or("Expected report.day to be a Day of report.year.")?;
+
+        let step = match json.get("step").and_then(|v| v.get::<String>()) {
+            Some(s) if s == "parse" => Step::Parse,
+            Some(s) if s == "both" => Step::Both,
+            Some(s) if s == "part" => json
+                .get("part")
+                .and_then(|v| v.get::<f64>())
+                .map(|&part| part as u8)
+                .filter(|part| *part == 1 || *part == 2)
+                .map(Step::Part)
+                .ok_or("Expected report.part to be 1 or 2.")?,
+            _ => return Err("Expected report.step to be parse, part or both.".into()),
+        };
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected report.stats to be present.")
+            .and_then(|v| {
+                BenchStats::try_from(v).map_err(|_| "Expected report.stats to be stats.")
+            })?;
+
+        let memory = match json.get("memory") {
+            Some(v) if !v.is_null() => Some(
+                MemoryStats::try_from(v)
+                    .map_err(|_| "Expected report.memory to be memory stats.")?,
+            ),
+            _ => None,
+        };
+
+        let expected = json
+            .get("expected")
+            .and_then(|v| v.get::<String>())
+            .cloned();
+
+        let timeout = match json.get("timeout_secs") {
+            None => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .and_then(|&secs| Duration::try_from_secs_f64(secs).ok())
+                    .ok_or("Expected report.timeout_secs to be a number.")?,
+            ),
+        };
+
+        let panic = match json.get("panic") {
+            None => None,
+            Some(v) => {
+                Some(Panic::try_from(v).map_err(|_| "Expected report.panic to be a panic.")?)
+            }
+        };
+
+        let warning = json.get("warning").and_then(|v| v.get::<String>()).cloned();
+
+        let check = match json.get("status").and_then(|v| v.get::<String>()) {
+            Some(s) if s == "pass" => AnswerCheck::Pass,
+            // a part that timed out or panicked fails if it has an accepted answer.
+            Some(s) if s == "timeout" || s == "panicked" => expected
+                .map_or(AnswerCheck::Unknown, |expected| AnswerCheck::Fail {
+                    expected,
+                }),
+            Some(s) if s == "fail" => AnswerCheck::Fail {
+                expected: expected.ok_or("Expected report.expected to be a string.")?,
+            },
+            Some(s) if ["solved", "unsolved", "parsed", "combined"].contains(&s.as_str()) => {
+                AnswerCheck::Unknown
+            }
+            _ => return Err("Expected report.status to be a known status.".into()),
+        };
+
+        Ok(PartReport {
+            puzzle,
+            step,
+            answer: answer.cloned(),
+            stats,
+            memory,
+            check,
+            timeout,
+            panic,
+            warning,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputFormat, PartReport, Step};
+    use crate::puzzle;
+    use crate::template::{AnswerCheck, BenchStats, MemoryStats, Panic};
+
+    fn report(answer: Option<&str>, check: AnswerCheck) -> PartReport {
+        PartReport {
+            answer: answer.map(Into::into),
+            stats: BenchStats::from_duration(Duration::from_nanos(74130), 10000),
+            check,
+            ..PartReport::empty(puzzle!(2024, 5), Step::Part(2))
+        }
+    }
+
+    fn panic() -> Panic {
+        Panic {
+            message: "called `Option::unwrap()` on a `None` value".into(),
+            location: Some("src/bin/05.rs:12:40".into()),
+        }
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!(
+            "jsonl".parse::<OutputFormat>().unwrap(),
+            OutputFormat::JsonLines
+        );
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn derives_status() {
+        assert_eq!(report(Some("1"), AnswerCheck::Pass).status(), "pass");
+        assert_eq!(report(None, AnswerCheck::Unknown).status(), "unsolved");
+        assert_eq!(report(Some("1"), AnswerCheck::Unknown).status(), "solved");
+        let fail = AnswerCheck::Fail {
+            expected: "2".into(),
+        };
+        assert_eq!(report(Some("1"), fail).status(), "fail");
+        let parse = PartReport {
+            step: Step::Parse,
+            ..report(None, AnswerCheck::Unknown)
+        };
+        assert_eq!(parse.status(), "parsed");
+        let both = PartReport {
+            step: Step::Both,
+            ..report(None, AnswerCheck::Unknown)
+        };
+        assert_eq!(both.status(), "combined");
+        let timed_out = PartReport {
+            timeout: Some(Duration::from_secs(5)),
+            ..report(None, AnswerCheck::Pass)
+        };
+        assert_eq!(timed_out.status(), "timeout");
+        let panicked = PartReport {
+            panic: Some(panic()),
+            ..report(None, AnswerCheck::Unknown)
+        };
+        assert_eq!(panicked.status(), "panicked");
+    }
+
+    #[test]
+    fn round_trips_json_lines() {
+        let reports = [
+            report(Some("42"), AnswerCheck::Pass),
+            report(None, AnswerCheck::Unknown),
+            report(
+                Some("41"),
+                AnswerCheck::Fail {
+                    expected: "42".into(),
+                },
+            ),
+            PartReport {
+                step: Step::Parse,
+                ..report(None, AnswerCheck::Unknown)
+            },
+            PartReport {
+                step: Step::Both,
+                ..report(None, AnswerCheck::Unknown)
+            },
+            PartReport {
+                timeout: Some(Duration::from_millis(1500)),
+                ..report(
+                    None,
+                    AnswerCheck::Fail {
+                        expected: "42".into(),
+                    },
+                )
+            },
+            PartReport {
+                timeout: Some(Duration::from_secs(2)),
+                ..report(None, AnswerCheck::Unknown)
+            },
+            PartReport {
+                warning: Some("-7 is negative, puzzle answers never are.".into()),
+                ..report(Some("-7"), AnswerCheck::Unknown)
+            },
+            PartReport {
+                panic: Some(panic()),
+                ..report(
+                    None,
+                    AnswerCheck::Fail {
+                        expected: "42".into(),
+                    },
+                )
+            },
+            PartReport {
+                panic: Some(Panic {
+                    location: None,
+                    ..panic()
+                }),
+                step: Step::Parse,
+                ..report(None, AnswerCheck::Unknown)
+            },
+            PartReport {
+                memory: Some(MemoryStats {
+                    peak_bytes: 1024,
+                    total_allocations: 3,
+                    total_bytes: 2048,
+                }),
+                ..report(Some("42"), AnswerCheck::Pass)
+            },
+        ];
+
+        for report in reports {
+            let line = report.to_json_line();
+            assert!(!line.contains('\n'));
+            assert_eq!(line.parse::<PartReport>().unwrap(), report);
+        }
+    }
+}
@@ -1,48 +1,212 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, fs, io, time::Duration};
+
+use crate::template::registry::{self, Solution};
+use crate::template::report::to_json_document;
+use crate::template::runner::RunOptions;
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Answers, OutputFormat, Panic, PartReport, PuzzleId, Step,
+    resolve_data_path,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use super::timings::{Timing, Timings};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
-};
+/// Outcome of running a set of days.
+pub struct MultiRun {
+    pub timings: Option<Timings>,
+    /// `true` if any part did not reproduce its accepted answer.
+    pub has_mismatch: bool,
+    /// `true` if any step panicked.
+    pub has_panic: bool,
+}
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Run a set of days and collect their timings if `is_timed`. With `is_memory`, every day is
+/// built with the `dhat-heap` feature instead and only the heap usage of its parts is collected.
+/// With a `timeout`, every day runs in its own process, which is stopped once a step exceeds it.
+pub fn run_multi(
+    puzzles_to_run: &HashSet<PuzzleId>,
+    is_release: bool,
+    is_timed: bool,
+    is_memory: bool,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+) -> MultiRun {
+    let mut timings: Vec<Timing> = Vec::with_capacity(puzzles_to_run.len());
+    let mut panics: Vec<(PuzzleId, Step, Panic)> = vec![];
+    let mut all_reports: Vec<PartReport> = vec![];
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
+    if let Err(e) = Answers::read_from_file() {
+        eprintln!("Answers are not checked: {e}");
+    }
+
+    // NOTE: use non-duplicate, sorted puzzle values.
+    let mut puzzles: Vec<PuzzleId> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
+
+    let is_multi_year = puzzles.windows(2).any(|w| w[0].year != w[1].year);
+
+    puzzles.into_iter().for_each(|puzzle| {
+        if format.is_text() {
             if need_space {
                 println!();
             }
             need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+            if is_multi_year {
+                println!("{ANSI_BOLD}{} Day {}{ANSI_RESET}", puzzle.year, puzzle.day);
+                println!("-----------");
+            } else {
+                println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+                println!("------");
+            }
+        }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        // the allocator of the current binary can not be swapped for the profiler,
+        // nor can a part that exceeds its timeout be stopped without stopping all days.
+        let is_in_process = !is_memory && timeout.is_none();
+        let reports = match registry::find(puzzle).filter(|_| is_in_process) {
+            Some(solution) => run_solution(solution, is_timed, format),
+            None => child_commands::run_solution(
+                puzzle, is_timed, is_release, is_memory, format, timeout,
+            )
+            .unwrap(),
+        };
 
-            if output.is_empty() {
+        if reports.is_empty() {
+            if format.is_text() {
                 println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
             }
-        });
+            return;
+        }
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+        timings.push(parse_exec_time(&reports, puzzle));
+
+        panics.extend(
+            reports
+                .iter()
+                .filter_map(|r| Some((puzzle, r.step, r.panic.clone()?))),
         );
+        all_reports.extend(reports);
+    });
+
+    let has_mismatch = all_reports.iter().any(|r| r.check.is_fail());
+
+    if format.is_text() {
+        print_panics(&panics, is_multi_year);
+    } else if format == OutputFormat::Json {
+        println!("{}", to_json_document(&all_reports));
+    }
+
+    let timings = if is_timed || is_memory {
+        let timings = Timings {
+            data: timings,
+            history: vec![],
+        };
+        if format.is_text() {
+            let total_millis = timings.total_millis();
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
+    };
+
+    MultiRun {
+        timings,
+        has_mismatch,
+        has_panic: !panics.is_empty(),
+    }
+}
+
+/// Run a solution that is compiled into the current binary, returns no reports if its input is missing.
+fn run_solution(solution: &Solution, is_timed: bool, format: OutputFormat) -> Vec<PartReport> {
+    let puzzle = solution.puzzle;
+    let input_path = resolve_data_path("inputs", puzzle.year, &format!("{}.txt", puzzle.day));
+    let Ok(input) = fs::read_to_string(&input_path)
+        .map_err(|e| eprintln!("Could not read \"{}\": {e}", input_path.display()))
+    else {
+        return vec![];
+    };
+
+    let options = RunOptions {
+        is_timed,
+        format,
+        timeout: None,
+        is_example: false,
+        params: vec![],
+    };
+    (solution.run)(&input, &options)
+}
+
+/// Build the timing of a puzzle from the reports of its steps.
+/// Only benched parsing, benched combined parts and benched, solved parts are timed.
+pub fn parse_exec_time(reports: &[PartReport], puzzle: PuzzleId) -> Timing {
+    let mut timings = Timing {
+        puzzle,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        combined: None,
+        memory: vec![],
+        timeouts: vec![],
+        total_nanos: 0_f64,
+    };
+
+    timings.memory = reports
+        .iter()
+        .filter(|r| r.puzzle == puzzle)
+        .filter_map(|r| Some((r.step, r.memory.clone()?)))
+        .collect();
+
+    timings.timeouts = reports
+        .iter()
+        .filter(|r| r.puzzle == puzzle)
+        .filter_map(|r| Some((r.step, r.timeout?)))
+        .collect();
+
+    reports
+        .iter()
+        .filter(|r| {
+            r.puzzle == puzzle
+                && (matches!(r.step, Step::Parse | Step::Both) || r.answer.is_some())
+                && r.stats.samples > 1
+        })
+        .for_each(|r| {
+            let stats = Some(r.stats.clone());
+            match r.step {
+                Step::Parse => timings.parse = stats,
+                Step::Part(1) => timings.part_1 = stats,
+                Step::Part(_) => timings.part_2 = stats,
+                Step::Both => timings.combined = stats,
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = r.stats.median.as_nanos() as f64;
+            timings.total_nanos += nanos;
+        });
+
+    timings
+}
+
+/// Print every step that panicked, unlike unsolved days these count as failures.
+fn print_panics(panics: &[(PuzzleId, Step, Panic)], is_multi_year: bool) {
+    if panics.is_empty() {
+        return;
+    }
+
+    println!("\n{ANSI_BOLD}Panics{ANSI_RESET}");
+    println!("------");
+
+    for (puzzle, step, panic) in panics {
+        let label = if is_multi_year {
+            format!("{} Day {}", puzzle.year, puzzle.day)
+        } else {
+            format!("Day {}", puzzle.day)
+        };
+        println!("{label} | {step}: {panic}");
     }
 }
 
@@ -59,47 +223,71 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// Solutions that are not compiled into the current binary live in isolated binaries.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading
+/// the reports they print with `--format jsonl`.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::runner::{print_report, print_report_result};
+    use crate::template::{
+        AnswerCheck, Answers, BenchStats, OutputFormat, PartReport, PuzzleId, Step, get_bin_name,
+        get_path_for_bin,
+    };
+    use std::collections::HashMap;
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::time::{Duration, Instant};
     use std::{
+        fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{Child, Command, Stdio},
         thread,
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// Time a bin gets on top of its timeout to stop itself before it is killed.
+    const KILL_GRACE: Duration = Duration::from_secs(1);
+
+    /// Run the solution bin for a given day and print its reports in the requested format.
+    /// With `is_memory`, the bin is built with the `dhat` profile to profile its heap usage.
+    /// With a `timeout`, the bin stops itself once a step exceeds it and reports it as timed out.
+    /// A bin that does not is killed once no step finished for the timeout (plus a grace period),
+    /// the step it was stuck in is then reported as timed out, see [`stuck_step`].
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        is_memory: bool,
+        format: OutputFormat,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // the bin is built first and run directly, so that compiling does not count towards
+        // the timeout and killing the process actually stops the solution.
+        let Some(executable) = build(puzzle, is_release, is_memory)? else {
+            return Ok(vec![]);
+        };
 
-        if is_release {
-            args.push("--release");
-        }
+        let mut args = vec!["--format", OutputFormat::JsonLines.as_str()];
 
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        let timeout_arg = timeout.map(|timeout| timeout.as_secs_f64().to_string());
+        if let Some(timeout) = &timeout_arg {
+            args.extend(["--timeout", timeout]);
+        }
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while grabbing the reports.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,7 +296,7 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut reports = vec![];
 
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
@@ -116,142 +304,368 @@ pub mod child_commands {
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut deadline = timeout.map(|timeout| Instant::now() + timeout + KILL_GRACE);
+
+        loop {
+            let line = match deadline {
+                Some(deadline) => {
+                    match lines.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                        Ok(line) => line,
+                        Err(RecvTimeoutError::Disconnected) => break,
+                        Err(RecvTimeoutError::Timeout) => {
+                            if let Some(timeout) = timeout
+                                && kill(&mut cmd)?
+                            {
+                                let report = timed_out(puzzle, &reports, timeout);
+                                if format.is_text() {
+                                    print_report_result(&report);
+                                }
+                                print_report(&report, format);
+                                reports.push(report);
+                            }
+                            break;
+                        }
+                    }
+                }
+                None => match lines.recv() {
+                    Ok(line) => line,
+                    Err(_) => break,
+                },
+            };
+
+            match parse_report(&line) {
+                Some(report) => {
+                    if format.is_text() {
+                        print_report_result(&report);
+                    }
+                    print_report(&report, format);
+                    reports.push(report);
+                    // every step gets the whole timeout.
+                    deadline = timeout.map(|timeout| Instant::now() + timeout + KILL_GRACE);
+                }
+                // keep machine-readable output clean of anything the solution prints.
+                None if format.is_text() => println!("{line}"),
+                None => eprintln!("{line}"),
+            }
         }
 
         thread.join().unwrap();
         cmd.wait()?;
 
-        Ok(output)
+        Ok(reports)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Builds the bin of a puzzle and returns the path of its executable,
+    /// `None` if it failed to build. Compiler errors are printed by cargo.
+    fn build(
+        puzzle: PuzzleId,
+        is_release: bool,
+        is_memory: bool,
+    ) -> Result<Option<PathBuf>, Error> {
+        let bin_name = get_bin_name(puzzle);
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            &bin_name,
+            "--message-format",
+            "json-render-diagnostics",
+        ];
+
+        if is_memory {
+            args.extend(["--profile", "dhat"]);
+        } else if is_release {
+            args.push("--release");
+        }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        let features = features(is_memory);
+        if !features.is_empty() {
+            args.extend(["--features", &features]);
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
 
-                timings.total_nanos += nanos;
-            });
+        if !output.status.success() {
+            return Ok(None);
+        }
 
-        timings
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.parse::<JsonValue>().ok())
+            .find_map(|message| {
+                let message: &HashMap<String, JsonValue> = message.get()?;
+                let path: &String = message.get("executable")?.get()?;
+                Some(PathBuf::from(path))
+            }))
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Kills a bin unless it exited in the meantime, returns whether it was killed.
+    fn kill(cmd: &mut Child) -> Result<bool, Error> {
+        if cmd.try_wait()?.is_some() {
+            return Ok(false);
+        }
+        cmd.kill()?;
+        Ok(true)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// The report of the step a killed bin was stuck in.
+    fn timed_out(puzzle: PuzzleId, reports: &[PartReport], timeout: Duration) -> PartReport {
+        let source = fs::read_to_string(get_path_for_bin(puzzle)).unwrap_or_default();
+        let step = stuck_step(&source, reports);
+
+        PartReport {
+            puzzle,
+            step,
+            answer: None,
+            stats: BenchStats::from_duration(timeout, 0),
+            memory: None,
+            check: match (step, Answers::read_from_file()) {
+                (Step::Part(part), Ok(answers)) => answers.verify(puzzle, part, None),
+                _ => AnswerCheck::Unknown,
+            },
+            timeout: Some(timeout),
+            panic: None,
+            warning: None,
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Returns the step a bin was stuck in, the one after the steps it reported. A bin that did
+    /// not report anything was stuck in its first step, which depends on the `solution!` invocation
+    /// in its `source`: parsing with `parse = …`, both parts with `solve = …`, part 1 otherwise.
+    pub fn stuck_step(source: &str, reports: &[PartReport]) -> Step {
+        if reports.is_empty() {
+            let declaration = source
+                .split_once("solution!(")
+                .and_then(|(_, rest)| rest.split_once(");"))
+                .map_or("", |(declaration, _)| declaration);
+
+            if declaration.contains("parse =") {
+                return Step::Parse;
+            }
+            if declaration.contains("solve =") {
+                return Step::Both;
+            }
+        }
+
+        let part = (1..=2)
+            .find(|part| reports.iter().all(|r| r.step != Step::Part(*part)))
+            .unwrap_or(2);
+        Step::Part(part)
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    /// Returns the features a solution bin is built with, as a single `--features` argument:
+    /// `dhat-heap` to profile its heap usage, plus the features of the current binary that
+    /// change how a solution runs, e.g. `http` to submit answers with the native client.
+    pub fn features(is_memory: bool) -> String {
+        let features = [
+            (is_memory, "dhat-heap"),
+            (cfg!(feature = "count-alloc"), "count-alloc"),
+            (cfg!(feature = "http"), "http"),
+        ];
+
+        features
+            .iter()
+            .filter(|(is_enabled, _)| *is_enabled)
+            .map(|(_, feature)| *feature)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+    /// Parse a line printed by a solution, returns `None` if the line is not a report.
+    pub fn parse_report(line: &str) -> Option<PartReport> {
+        if !line.starts_with('{') {
+            return None;
         }
+        line.parse().ok()
+    }
+}
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::child_commands::{features, parse_report, stuck_step};
+    use super::parse_exec_time;
+    use crate::puzzle;
+    use crate::template::{BenchStats, MemoryStats, PartReport, Step};
+
+    fn report(part: u8, answer: Option<&str>, duration: Duration, samples: u128) -> PartReport {
+        PartReport {
+            answer: answer.map(Into::into),
+            stats: BenchStats::from_duration(duration, samples),
+            ..PartReport::empty(puzzle!(2025, 1), Step::Part(part))
         }
     }
+
+    #[test]
+    fn parses_execution_times() {
+        let res = parse_exec_time(
+            &[
+                report(1, Some("0"), Duration::from_nanos(74), 100_000),
+                report(2, Some("10"), Duration::from_nanos(74_130_000), 99999),
+            ],
+            puzzle!(2025, 1),
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap().median, Duration::from_nanos(74));
+        assert_eq!(res.part_2.unwrap().samples, 99999);
+    }
+
+    #[test]
+    fn parses_with_patterns_in_input() {
+        let line = report(
+            1,
+            Some("@ @ @ ( ) ms (2s @ 5 samples)"),
+            Duration::from_secs(2),
+            5,
+        )
+        .to_json_line();
+        let parsed = parse_report(&line).unwrap();
+
+        let res = parse_exec_time(&[parsed], puzzle!(2025, 1));
+        assert_eq!(res.total_nanos, 2_000_000_000_f64);
+        assert_eq!(res.part_1.unwrap().median, Duration::from_secs(2));
+        assert!(res.part_2.is_none());
+    }
+
+    #[test]
+    fn parses_missing_parts() {
+        let res = parse_exec_time(
+            &[
+                report(1, None, Duration::from_millis(1), 10),
+                report(2, None, Duration::from_millis(1), 10),
+            ],
+            puzzle!(2025, 1),
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
+    }
+
+    #[test]
+    fn parses_parse_time() {
+        let parse = PartReport {
+            step: Step::Parse,
+            answer: None,
+            ..report(1, None, Duration::from_nanos(26), 100)
+        };
+        let res = parse_exec_time(
+            &[parse, report(1, Some("0"), Duration::from_nanos(74), 100)],
+            puzzle!(2025, 1),
+        );
+        assert_eq!(res.total_nanos, 100_f64);
+        assert_eq!(res.parse.unwrap().median, Duration::from_nanos(26));
+        assert!(res.part_2.is_none());
+    }
+
+    #[test]
+    fn parses_combined_time() {
+        let both = PartReport {
+            step: Step::Both,
+            ..report(1, None, Duration::from_nanos(50), 100)
+        };
+        let res = parse_exec_time(
+            &[
+                both,
+                report(1, Some("1"), Duration::ZERO, 0),
+                report(2, Some("2"), Duration::ZERO, 0),
+            ],
+            puzzle!(2025, 1),
+        );
+        assert_eq!(res.total_nanos, 50_f64);
+        assert_eq!(res.combined.unwrap().median, Duration::from_nanos(50));
+        assert!(res.part_1.is_none() && res.part_2.is_none());
+    }
+
+    #[test]
+    fn parses_memory() {
+        let memory = MemoryStats {
+            peak_bytes: 1024,
+            total_allocations: 2,
+            total_bytes: 2048,
+        };
+        let part_2 = PartReport {
+            memory: Some(memory.clone()),
+            ..report(2, Some("10"), Duration::from_millis(1), 1)
+        };
+        let res = parse_exec_time(
+            &[report(1, Some("0"), Duration::from_millis(1), 1), part_2],
+            puzzle!(2025, 1),
+        );
+        assert!(res.part_2.is_none());
+        assert_eq!(res.memory(Step::Part(1)), None);
+        assert_eq!(res.memory(Step::Part(2)), Some(&memory));
+    }
+
+    #[test]
+    fn parses_timeouts() {
+        let timed_out = PartReport {
+            timeout: Some(Duration::from_secs(5)),
+            ..report(2, None, Duration::from_secs(5), 0)
+        };
+        let res = parse_exec_time(
+            &[
+                report(1, Some("0"), Duration::from_millis(1), 10),
+                timed_out,
+            ],
+            puzzle!(2025, 1),
+        );
+        assert!(res.part_2.is_none());
+        assert_eq!(res.timeout(Step::Part(1)), None);
+        assert_eq!(res.timeout(Step::Part(2)), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn parses_reports() {
+        let line = report(1, Some("42"), Duration::from_nanos(74), 100_000).to_json_line();
+
+        assert_eq!(parse_report(&line).unwrap().answer, Some("42".into()));
+        assert!(parse_report("Part 1: \x1b[1m42\x1b[0m (74.13ns @ 100000 samples)").is_none());
+        assert!(parse_report("{ debug output").is_none());
+    }
+
+    #[test]
+    fn forwards_features_as_one_argument() {
+        assert!(features(true).starts_with("dhat-heap"));
+        assert_eq!(
+            features(true).split(',').any(|f| f == "http"),
+            cfg!(feature = "http")
+        );
+        assert!(!features(false).contains("dhat-heap"));
+    }
+
+    #[test]
+    fn infers_the_stuck_step() {
+        let plain = "advent_of_code::solution!(1);";
+        let parsed = "advent_of_code::solution!(1, parse = parse);";
+        let both = "advent_of_code::solution!(year = 2024, 1, solve = solve);";
+        let parse = PartReport {
+            step: Step::Parse,
+            ..report(1, None, Duration::ZERO, 0)
+        };
+
+        assert_eq!(stuck_step(plain, &[]), Step::Part(1));
+        assert_eq!(stuck_step(parsed, &[]), Step::Parse);
+        assert_eq!(stuck_step(both, &[]), Step::Both);
+        assert_eq!(stuck_step(parsed, &[parse]), Step::Part(1));
+        assert_eq!(
+            stuck_step(plain, &[report(1, Some("0"), Duration::ZERO, 0)]),
+            Step::Part(2)
+        );
+        assert_eq!(stuck_step("", &[]), Step::Part(1));
+    }
 }
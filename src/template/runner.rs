@@ -2,96 +2,535 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process, thread};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::bench::{BenchConfig, BenchStats, MIN_SAMPLES};
+use crate::template::registry::Solution;
+use crate::template::report::to_json_document;
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Answer, AnswerCheck, Answers, MemoryStats, OutputFormat, Panic,
+    PartReport, PuzzleId, Step, Verdict, aoc_cli,
+};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Exit code of a solution binary that was stopped by its timeout, like coreutils' `timeout`.
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// How solutions are run, read from the arguments of a solution binary or passed by `all` and `time`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    pub format: OutputFormat,
+    /// Stops the process if the first run of a step takes longer than this, benchmarks end early to fit in.
+    pub timeout: Option<Duration>,
+    /// Whether the input is an example, whose answers are not compared to the accepted ones.
+    pub is_example: bool,
+    /// Parameters that take precedence over those declared by the solution, see [`crate::template::Context`].
+    pub params: Vec<(String, String)>,
+}
+
+impl RunOptions {
+    pub fn from_args() -> Self {
+        Self {
+            is_timed: env::args().any(|x| x == "--time"),
+            format: OutputFormat::from_args(),
+            timeout: timeout_from_args(),
+            is_example: false,
+            params: vec![],
+        }
+    }
+}
+
+/// Reads the `--timeout <secs>` argument of the current process, see [`timeout_from_env`].
+fn timeout_from_args() -> Option<Duration> {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(index) = args.iter().position(|x| x == "--timeout") else {
+        return timeout_from_env();
+    };
+
+    match args.get(index + 1).and_then(|x| parse_timeout(x)) {
+        Some(timeout) => Some(timeout),
+        None => {
+            eprintln!("Unexpected command-line input. Format: --timeout <secs>");
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the timeout configured with `AOC_TIMEOUT_SECS`, steps run without a timeout by default.
+pub fn timeout_from_env() -> Option<Duration> {
+    env::var("AOC_TIMEOUT_SECS")
+        .ok()
+        .and_then(|x| parse_timeout(&x))
+}
+
+/// Parses a positive number of seconds, e.g. `2.5`.
+pub fn parse_timeout(s: &str) -> Option<Duration> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+}
+
+/// Entry point of a solution binary: runs the solution, tries to submit an answer if requested,
+/// then exits with a non-zero status if any part panicked or did not reproduce its accepted answer.
+pub fn run_solution(solution: &Solution, input: &str) {
+    let options = RunOptions::from_args();
+    if let Err(e) = Answers::read_from_file() {
+        eprintln!("Answers are not checked: {e}");
+    }
+    let reports = (solution.run)(input, &options);
+
+    for report in &reports {
+        if let (Step::Part(part), Some(answer)) = (report.step, &report.answer) {
+            submit_result(
+                answer,
+                report.warning.as_deref(),
+                report.puzzle,
+                part,
+                options.format,
+            );
+        }
+    }
+
+    if options.format == OutputFormat::Json {
+        println!("{}", to_json_document(&reports));
+    }
+
+    if reports
+        .iter()
+        .any(|r| r.check.is_fail() || r.panic.is_some())
+    {
+        process::exit(1);
+    }
+}
+
+/// Run a solution part, print its result and compare it against its accepted answer.
+/// A panic of the part is caught and reported, so that the next part still runs.
+pub fn run_part<I: Copy, T: Answer>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport {
+    let step = Step::Part(part);
+    let format = options.format;
+
+    let (result, stats, memory) = run_timed(func, input, puzzle, step, options, |result| {
+        if format.is_text() {
+            print_result(
+                &result.as_ref().map(T::display),
+                &step.to_string(),
+                None,
+                &AnswerCheck::Unknown,
+            );
+            print_benching(options.is_timed);
+        }
+    });
+
+    let (result, panic) = match result {
+        Ok(result) => (result, None),
+        Err(panic) => (None, Some(panic)),
+    };
+
+    let answer = result.as_ref().map(T::submission);
+    let report = PartReport {
+        puzzle,
+        step,
+        check: verify(puzzle, part, answer.as_deref(), options),
+        answer,
+        stats,
+        memory,
+        timeout: None,
+        panic,
+        warning: result.as_ref().and_then(T::warning),
+    };
+    if format.is_text() {
+        match &result {
+            // answers may be printed differently than they are submitted, e.g. a grid of letters.
+            Some(result) => print_result(
+                &Some(result.display()),
+                &step.to_string(),
+                Some(&format_stats(&report.stats, report.memory.as_ref())),
+                &report.check,
+            ),
+            None => print_report_result(&report),
+        }
+    }
+    print_report(&report, format);
+    report
+}
+
+/// Parse the input that is shared by both parts and print how long it took.
+/// Returns the parsed input along with its report, the parsed input is `None` if parsing panicked.
+pub fn run_parse<I: Copy, P>(
+    func: impl Fn(I) -> P,
+    input: I,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+) -> (Option<P>, PartReport) {
+    let format = options.format;
+
+    let (parsed, stats, memory) = run_timed(func, input, puzzle, Step::Parse, options, |_| {
+        if format.is_text() && options.is_timed {
+            print!("{}:", Step::Parse);
+            print_benching(true);
+        }
+    });
+
+    let report = PartReport {
+        puzzle,
+        step: Step::Parse,
+        answer: None,
+        stats,
+        memory,
+        check: AnswerCheck::Unknown,
+        timeout: None,
+        panic: parsed.as_ref().err().cloned(),
+        warning: None,
+    };
+    if format.is_text() {
+        print_report_result(&report);
+    }
+    print_report(&report, format);
+
+    (parsed.ok(), report)
+}
+
+/// Run a solution that solves both parts at once, print both answers and compare them against
+/// their accepted answers. The parts are timed together in a [`Step::Both`] report, the reports
+/// of the parts themselves take no samples.
+pub fn run_both<I: Copy, A: Answer, B: Answer>(
+    func: impl Fn(I) -> (Option<A>, Option<B>),
+    input: I,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+) -> Vec<PartReport> {
+    let format = options.format;
+
+    let (result, stats, memory) = run_timed(func, input, puzzle, Step::Both, options, |_| {
+        if format.is_text() && options.is_timed {
+            print!("{}:", Step::Both);
+            print_benching(true);
+        }
+    });
+
+    let (panic, (part_1, part_2)) = match result {
+        Ok(answers) => (None, answers),
+        Err(panic) => (Some(panic), (None, None)),
+    };
+
+    let part_report = |part: u8, answer: Option<&dyn Answer>| PartReport {
+        puzzle,
+        step: Step::Part(part),
+        check: verify(
+            puzzle,
+            part,
+            answer.map(Answer::submission).as_deref(),
+            options,
+        ),
+        answer: answer.map(Answer::submission),
+        stats: BenchStats::from_duration(Duration::ZERO, 0),
+        memory: None,
+        timeout: None,
+        panic: None,
+        warning: answer.and_then(Answer::warning),
+    };
+
+    let reports = vec![
+        PartReport {
+            puzzle,
+            step: Step::Both,
+            answer: None,
+            stats,
+            memory,
+            check: AnswerCheck::Unknown,
+            timeout: None,
+            panic,
+            warning: None,
+        },
+        part_report(1, part_1.as_ref().map(|x| x as &dyn Answer)),
+        part_report(2, part_2.as_ref().map(|x| x as &dyn Answer)),
+    ];
+
+    for report in &reports {
+        if format.is_text() {
+            print_report_result(report);
+        }
+        print_report(report, format);
+    }
+
+    reports
+}
+
+/// Compares the answer of a part against its accepted answer, unless the input is an example.
+fn verify(puzzle: PuzzleId, part: u8, answer: Option<&str>, options: &RunOptions) -> AnswerCheck {
+    if options.is_example {
+        AnswerCheck::Unknown
+    } else {
+        // an unreadable ledger is reported once by `run_solution`.
+        Answers::read_from_file().map_or(AnswerCheck::Unknown, |answers| {
+            answers.verify(puzzle, part, answer)
+        })
+    }
+}
+
+fn print_benching(is_timed: bool) {
+    if is_timed {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+}
+
+/// Print the result line of a report, e.g. for a part that was run in a child process.
+pub fn print_report_result(report: &PartReport) {
+    if let Some(timeout) = report.timeout {
+        print!("\r");
+        println!(
+            "{}: {ANSI_BOLD}timed out after {timeout:.1?}{ANSI_RESET}",
+            report.step
+        );
+        return;
+    }
+
+    if let Some(panic) = &report.panic {
+        print!("\r");
+        println!("{}: {ANSI_BOLD}panicked: {panic}{ANSI_RESET}", report.step);
+        return;
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    match report.step {
+        Step::Parse | Step::Both => {
+            print!("\r");
+            println!(
+                "{}:{}",
+                report.step,
+                format_stats(&report.stats, report.memory.as_ref())
+            );
+        }
+        // parts that were timed together with the other part have no time of their own.
+        Step::Part(_) if report.stats.samples == 0 => {
+            print_result(
+                &report.answer,
+                &report.step.to_string(),
+                Some(""),
+                &report.check,
+            );
+        }
+        Step::Part(_) => print_result(
+            &report.answer,
+            &report.step.to_string(),
+            Some(&format_stats(&report.stats, report.memory.as_ref())),
+            &report.check,
+        ),
+    }
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+/// Print the outcome of a part that is not covered by its result line: the warning of its answer
+/// as text or the whole report as a JSON line. `--format json` prints all reports at once instead.
+pub fn print_report(report: &PartReport, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => {
+            if let Some(warning) = &report.warning {
+                println!("  ⚠ {warning}");
+            }
+        }
+        OutputFormat::JsonLines => println!("{}", report.to_json_line()),
+        OutputFormat::Json => {}
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether we are timing the run:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched after warming up, see [`BenchConfig`].
+///
+/// With the `dhat-heap` or the `count-alloc` feature, the heap usage of the first run is measured as well.
+/// If the first run exceeds the timeout, the step is reported as timed out and the process stops.
+/// Benchmarks stop sampling before the timeout, with fewer than [`MIN_SAMPLES`] samples if need be.
+/// If the first run panics, the panic is returned without any samples.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    puzzle: PuzzleId,
+    step: Step,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+) -> (Result<T, Panic>, BenchStats, Option<MemoryStats>) {
+    let is_timed = options.is_timed;
+
+    let start = Instant::now();
+    let watchdog = Watchdog::start(puzzle, step, options);
+    let first_run = Panic::catch(|| {
+        MemoryStats::measure(|| {
+            let timer = Instant::now();
+            let result = func(input);
+            (result, timer.elapsed())
+        })
+    });
 
-        func(input)
+    let ((result, base_time), memory) = match first_run {
+        Ok(first_run) => first_run,
+        Err(panic) => {
+            return (
+                Err(panic),
+                BenchStats::from_duration(Duration::ZERO, 0),
+                None,
+            );
+        }
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if is_timed {
+        let deadline = options.timeout.map(|timeout| start + timeout);
+        bench(func, input, base_time, &BenchConfig::from_env(), deadline)
     } else {
-        (base_time, 1)
+        BenchStats::from_duration(base_time, 1)
     };
+    drop(watchdog);
 
-    (result, run.0, run.1)
+    (Ok(result), stats, memory)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Stops the process once a step exceeds its timeout, unless it is dropped before.
+struct Watchdog {
+    done: Sender<()>,
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+impl Watchdog {
+    fn start(puzzle: PuzzleId, step: Step, options: &RunOptions) -> Option<Self> {
+        let timeout = options.timeout?;
+        let format = options.format;
+        let options = options.clone();
+        let (done, receiver) = mpsc::channel();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+        thread::spawn(move || {
+            if receiver.recv_timeout(timeout) != Err(RecvTimeoutError::Timeout) {
+                return;
+            }
+
+            let part = match step {
+                Step::Part(part) => Some(part),
+                Step::Parse | Step::Both => None,
+            };
+            let report = PartReport {
+                puzzle,
+                step,
+                answer: None,
+                stats: BenchStats::from_duration(timeout, 0),
+                memory: None,
+                check: part.map_or(AnswerCheck::Unknown, |part| {
+                    verify(puzzle, part, None, &options)
+                }),
+                timeout: Some(timeout),
+                panic: None,
+                warning: None,
+            };
+
+            if format.is_text() {
+                print_report_result(&report);
+            }
+            print_report(&report, format);
+            if format == OutputFormat::Json {
+                println!("{}", to_json_document(&[report]));
+            }
+
+            process::exit(TIMEOUT_EXIT_CODE);
+        });
+
+        Some(Self { done })
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        let _ = self.done.send(());
+    }
+}
+
+/// Sample a function until the time budget is spent (but at least [`MIN_SAMPLES`] times) or the
+/// sample cap is reached. `base_time` is the duration of the first, cold run.
+///
+/// No run is started that would likely end after `deadline`, even below [`MIN_SAMPLES`]. Without
+/// any sample, the stats only hold the first run.
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: Duration,
+    config: &BenchConfig,
+    deadline: Option<Instant>,
+) -> BenchStats {
+    let fits = |run: Duration| deadline.is_none_or(|deadline| Instant::now() + run < deadline);
+
+    let mut warmup_time = base_time;
+    let mut last_run = base_time;
+    for _ in 0..config.warmup {
+        if warmup_time >= config.budget / 10 || !fits(last_run) {
+            break;
+        }
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        last_run = timer.elapsed();
+        warmup_time += last_run;
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let start = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while (timers.len() as u128) < config.max_samples
+        && ((timers.len() as u128) < MIN_SAMPLES || start.elapsed() < config.budget)
+        && fits(last_run)
+    {
         let timer = Instant::now();
         black_box(func(black_box(input)));
-        timers.push(timer.elapsed());
+        last_run = timer.elapsed();
+        timers.push(last_run);
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    if timers.is_empty() {
+        return BenchStats::from_duration(base_time, 1);
+    }
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+/// Formats the duration of a step, followed by its heap usage if it was measured.
+fn format_stats(stats: &BenchStats, memory: Option<&MemoryStats>) -> String {
+    let memory = memory.map_or_else(String::new, |memory| format!(" | {memory}"));
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+    if stats.samples <= 1 {
+        format!(" ({:.1?}){memory}", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ± {:.1?} @ {} samples, min {:.1?}, p95 {:.1?}, max {:.1?}, {} outliers){memory}",
+            stats.median,
+            stats.stddev,
+            stats.samples,
+            stats.min,
+            stats.p95,
+            stats.max,
+            stats.outliers
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
-    let is_intermediate_result = duration_str.is_empty();
+/// Print the result of a part, `duration_str` is `None` for the intermediate result printed while benching.
+/// The final result is followed by the check against the accepted answer of the part, if any.
+fn print_result<T: Display>(
+    result: &Option<T>,
+    part: &str,
+    duration_str: Option<&str>,
+    check: &AnswerCheck,
+) {
+    let is_intermediate_result = duration_str.is_none();
+    let duration_str = duration_str.unwrap_or_default();
+    let check = match check {
+        AnswerCheck::Unknown => String::new(),
+        check => format!(" | {check}"),
+    };
 
     match result {
         Some(result) => {
@@ -101,7 +540,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     print!("{str}");
                 } else {
                     print!("\r");
-                    println!("{str}");
+                    println!("{str}{check}");
                     println!("{result}");
                 }
             } else {
@@ -110,7 +549,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     print!("{str}");
                 } else {
                     print!("\r");
-                    println!("{str}");
+                    println!("{str}{check}");
                 }
             }
         }
@@ -119,7 +558,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 print!("{part}: ✖");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{check}             ");
             }
         }
     }
@@ -127,16 +566,22 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+///  2. aoc-cli is installed or the `http` feature is enabled.
+///  3. the answer has no warning, see [`Answer::warning`].
+///  4. the answer ledger does not already rule the answer out.
+///
+/// The verdict is recorded in the answer ledger.
+fn submit_result(
+    answer: &str,
+    warning: Option<&str>,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+    format: OutputFormat,
+) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,16 +597,135 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
+    }
+
+    if let Some(warning) = warning {
+        print_note(&format!("Not submitting \"{answer}\": {warning}"), format);
+        return;
+    }
+
+    // the verdict history would be lost if a ledger that can not be read was overwritten.
+    let mut answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Not submitting \"{answer}\": {e}");
+            return;
+        }
+    };
+
+    if let Err(refusal) = answers.check(puzzle, part, answer) {
+        print_note(&format!("Not submitting \"{answer}\": {refusal}"), format);
+        return;
     }
 
+    let Some(message) = send_answer(puzzle, part, answer, format) else {
+        return;
+    };
+    print_note(message.trim_end(), format);
+
+    match Verdict::from_response(&message) {
+        Some(verdict) => {
+            print_note(
+                &format!("Verdict: {ANSI_BOLD}{verdict}{ANSI_RESET}"),
+                format,
+            );
+            answers.record(puzzle, part, answer, verdict);
+            if let Err(e) = answers.store_file() {
+                eprintln!("Failed to store answer: {e}");
+            }
+        }
+        None => {
+            eprintln!("Could not determine the verdict, the answer was not recorded.");
+        }
+    }
+}
+
+/// Submits an answer via aoc-cli, or natively if aoc-cli is missing, returning the response message.
+fn send_answer(puzzle: PuzzleId, part: u8, answer: &str, format: OutputFormat) -> Option<String> {
     if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+        #[cfg(feature = "http")]
+        {
+            print_note("Submitting result via adventofcode.com...", format);
+            return crate::template::aoc_client::submit(puzzle, part, answer)
+                .map_err(|e| eprintln!("failed to submit result: {e}"))
+                .ok();
+        }
+
+        #[cfg(not(feature = "http"))]
+        {
+            eprintln!(
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it \
+                or enable the \"http\" feature."
+            );
+            process::exit(1);
+        }
+    }
+
+    print_note("Submitting result via aoc-cli...", format);
+    aoc_cli::submit(puzzle, part, answer)
+        .map_err(|e| eprintln!("failed to call aoc-cli: {e}"))
+        .ok()
+}
+
+/// Prints a message about submitting, to stderr if stdout is kept for a machine-readable format.
+fn print_note(line: &str, format: OutputFormat) {
+    if format.is_text() {
+        println!("{line}");
+    } else {
+        eprintln!("{line}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::bench;
+    use crate::template::bench::{BenchConfig, MIN_SAMPLES};
+
+    fn sleep(millis: u64) {
+        thread::sleep(Duration::from_millis(millis));
+    }
+
+    #[test]
+    fn benches_at_least_min_samples() {
+        let stats = bench(|_| (), (), Duration::ZERO, &BenchConfig::default(), None);
+        assert!(stats.samples >= MIN_SAMPLES);
+    }
+
+    #[test]
+    fn stops_benching_before_the_deadline() {
+        let config = BenchConfig {
+            warmup: 0,
+            ..BenchConfig::default()
+        };
+        let start = Instant::now();
+        let stats = bench(
+            sleep,
+            20,
+            Duration::from_millis(20),
+            &config,
+            Some(start + Duration::from_millis(100)),
         );
-        process::exit(1);
+
+        assert!(start.elapsed() < Duration::from_millis(150));
+        assert!(stats.samples > 1 && stats.samples < MIN_SAMPLES);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn keeps_the_first_run_without_time_left() {
+        let stats = bench(
+            sleep,
+            20,
+            Duration::from_millis(20),
+            &BenchConfig::default(),
+            Some(Instant::now()),
+        );
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.median, Duration::from_millis(20));
+    }
 }
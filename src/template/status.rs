@@ -0,0 +1,310 @@
+/// Overview of the days of a year: which are scaffolded, have their data files, accepted answers,
+/// passing tests and benchmarks. Also regenerates the checklist of the readme from it.
+use std::{fs, path::Path, time::Duration};
+
+use crate::template::readme_benchmarks::{Error, replace_table};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Answers, PuzzleId, examples, get_path_for_bin, resolve_data_path};
+
+static MARKER: &str = "<!--- status checklist --->";
+
+/// What has been done for a day.
+#[derive(Clone, Debug)]
+pub struct DayStatus {
+    pub puzzle: PuzzleId,
+    pub is_scaffolded: bool,
+    pub has_input: bool,
+    /// Whether the day has an example file or examples listed in its manifest.
+    pub has_examples: bool,
+    /// Whether the answers of part 1 and 2 were accepted.
+    pub accepted: [bool; 2],
+    /// Whether the tests of the day pass, `None` if they were not run.
+    pub tests: Option<bool>,
+    pub timing: Option<Timing>,
+}
+
+impl DayStatus {
+    /// Reads the status of a day from its files, the answer ledger and the stored timings.
+    pub fn read(puzzle: PuzzleId, answers: &Answers, timings: &Timings) -> Self {
+        let file_name = format!("{}.txt", puzzle.day);
+        let is_filled =
+            |path: &Path| fs::read_to_string(path).is_ok_and(|content| !content.trim().is_empty());
+
+        Self {
+            puzzle,
+            is_scaffolded: Path::new(&get_path_for_bin(puzzle)).exists(),
+            has_input: is_filled(&resolve_data_path("inputs", puzzle.year, &file_name)),
+            has_examples: is_filled(&resolve_data_path("examples", puzzle.year, &file_name))
+                || examples::read(puzzle).is_ok_and(|examples| !examples.is_empty()),
+            accepted: [1, 2].map(|part| answers.correct_answer(puzzle, part).is_some()),
+            tests: None,
+            timing: timings.get(puzzle).cloned(),
+        }
+    }
+}
+
+/// Formats the status of days as a table for the terminal.
+pub fn format_table(statuses: &[DayStatus]) -> String {
+    let check = |value: bool| if value { "✔" } else { "✖" };
+
+    let mut lines = vec![format!(
+        "{:<8}{:<10}{:<7}{:<10}{:<8}{:<8}{:<7}{}",
+        "Day", "Solution", "Input", "Examples", "Part 1", "Part 2", "Tests", "Benchmark"
+    )];
+
+    for status in statuses {
+        let tests = match status.tests {
+            Some(true) => "pass",
+            Some(false) => "fail",
+            None => "-",
+        };
+        let benchmark = status.timing.as_ref().map_or("-".into(), |timing| {
+            format!("{:.1?}", Duration::from_nanos(timing.total_nanos as u64))
+        });
+
+        lines.push(format!(
+            "{:<8}{:<10}{:<7}{:<10}{:<8}{:<8}{:<7}{}",
+            format!("Day {}", status.puzzle.day.into_inner()),
+            check(status.is_scaffolded),
+            check(status.has_input),
+            check(status.has_examples),
+            check(status.accepted[0]),
+            check(status.accepted[1]),
+            tests,
+            benchmark
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Builds the checklist of days, ticking off days and parts with accepted answers and adding
+/// the benchmark of each part. Notes written below a day in `previous` are kept, as are parts
+/// ticked off and benchmarks noted there that are missing from the ledger and timings.
+fn construct_checklist(statuses: &[DayStatus], previous: &str) -> String {
+    let mut lines: Vec<String> = vec![MARKER.into()];
+
+    for status in statuses {
+        let day = status.puzzle.day.into_inner();
+        let block = day_block(previous, day);
+        let check = |value: bool| if value { "[x]" } else { "[ ]" };
+
+        let mut parts = vec![];
+        for (part, is_accepted) in (1..=2).zip(status.accepted) {
+            let benchmark = status.timing.as_ref().and_then(|timing| {
+                let (stats, suffix) = match (part, &timing.part_1, &timing.part_2) {
+                    (1, Some(stats), _) | (2, _, Some(stats)) => (stats, ""),
+                    // parts solved at once share a single timing.
+                    (1, None, None) => (timing.combined.as_ref()?, " for both parts"),
+                    _ => return None,
+                };
+                Some(format!(
+                    ": {:.1?}{suffix} @ {} samples",
+                    stats.median, stats.samples
+                ))
+            });
+
+            let (was_ticked, previous_benchmark) = previous_part(&block, part);
+            let is_done = is_accepted || was_ticked;
+            if let Some(benchmark) = benchmark
+                .or(previous_benchmark)
+                .or(is_done.then(String::new))
+            {
+                parts.push((
+                    is_done,
+                    format!("  - {} Part {part}{benchmark}", check(is_done)),
+                ));
+            }
+        }
+
+        let is_day_done = parts.len() == 2 && parts.iter().all(|(is_done, _)| *is_done);
+        lines.push(format!("- {} Day{day}", check(is_day_done)));
+        lines.extend(parts.into_iter().map(|(_, line)| line));
+        lines.extend(
+            block
+                .iter()
+                .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with("- ["))
+                .map(|line| (*line).to_string()),
+        );
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+/// Returns the lines written below a day of a checklist.
+fn day_block(checklist: &str, day: u8) -> Vec<&str> {
+    let heading = format!("Day{day}");
+    let is_day = |line: &str| line.starts_with("- [") && line.contains("] Day");
+
+    checklist
+        .lines()
+        .skip_while(|line| !(is_day(line) && line.trim_end().ends_with(&heading)))
+        .skip(1)
+        .take_while(|line| !is_day(line) && !line.starts_with(MARKER))
+        .collect()
+}
+
+/// Returns whether a part is ticked off in the lines of a day and the benchmark noted after it,
+/// e.g. `: 25.6µs @ 10000 samples`.
+fn previous_part(block: &[&str], part: u8) -> (bool, Option<String>) {
+    let name = format!("Part {part}");
+
+    block
+        .iter()
+        .find_map(|line| {
+            let (check, rest) = line.trim_start().strip_prefix("- [")?.split_once("] ")?;
+            let benchmark = rest.strip_prefix(&name)?;
+            Some((
+                check == "x",
+                (!benchmark.is_empty()).then(|| benchmark.to_string()),
+            ))
+        })
+        .unwrap_or_default()
+}
+
+fn update_content(readme: &mut String, statuses: &[DayStatus]) -> Result<(), Error> {
+    let checklist = construct_checklist(statuses, readme);
+    replace_table(readme, MARKER, &checklist)
+}
+
+/// Regenerates the checklist of the readme, between two `<!--- status checklist --->` markers.
+pub fn update_readme(statuses: &[DayStatus]) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, statuses)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DayStatus, MARKER, format_table, update_content};
+    use crate::template::timings::Timing;
+    use crate::template::{BenchStats, Day, PuzzleId};
+    use crate::year;
+
+    fn status(day: u8, accepted: [bool; 2], timing: Option<Timing>) -> DayStatus {
+        DayStatus {
+            puzzle: PuzzleId::new(year!(2025), Day::new(day).unwrap()),
+            is_scaffolded: true,
+            has_input: true,
+            has_examples: false,
+            accepted,
+            tests: Some(true),
+            timing,
+        }
+    }
+
+    fn timing(day: u8, part_1: Option<u64>, part_2: Option<u64>) -> Timing {
+        let stats = |micros| BenchStats::from_duration(Duration::from_micros(micros), 100);
+        Timing {
+            puzzle: PuzzleId::new(year!(2025), Day::new(day).unwrap()),
+            parse: None,
+            part_1: part_1.map(stats),
+            part_2: part_2.map(stats),
+            combined: None,
+            memory: vec![],
+            timeouts: vec![],
+            total_nanos: 3e6,
+        }
+    }
+
+    #[test]
+    fn formats_tables() {
+        let table = format_table(&[status(1, [true, false], Some(timing(1, Some(1), None)))]);
+        assert_eq!(
+            table,
+            "Day     Solution  Input  Examples  Part 1  Part 2  Tests  Benchmark\n\
+             Day 1   ✔         ✔      ✖         ✔       ✖       pass   3.0ms"
+        );
+    }
+
+    #[test]
+    fn updates_checklists() {
+        let mut readme = [
+            "## When will I get bored?",
+            "",
+            MARKER,
+            "- [ ] Day1",
+            "  - [ ] Part 1: 1.0s @ 1 samples",
+            "  - Union-find ftw!",
+            "  needs cleanup.",
+            "- [ ] Day2",
+            "- [x] Day4",
+            "  - [x] Part 1: 4.9µs @ 10000 samples",
+            "  - [x] Part 2",
+            MARKER,
+            "",
+        ]
+        .join("\n");
+
+        let statuses = [
+            status(1, [true, true], Some(timing(1, Some(25), Some(1500)))),
+            status(2, [true, false], None),
+            status(3, [false, false], None),
+            status(4, [false, false], None),
+        ];
+        update_content(&mut readme, &statuses).unwrap();
+
+        let expected = [
+            "## When will I get bored?",
+            "",
+            MARKER,
+            "- [x] Day1",
+            "  - [x] Part 1: 25.0µs @ 100 samples",
+            "  - [x] Part 2: 1.5ms @ 100 samples",
+            "  - Union-find ftw!",
+            "  needs cleanup.",
+            "- [ ] Day2",
+            "  - [x] Part 1",
+            "- [ ] Day3",
+            "- [x] Day4",
+            "  - [x] Part 1: 4.9µs @ 10000 samples",
+            "  - [x] Part 2",
+            MARKER,
+            "",
+        ]
+        .join("\n");
+        assert_eq!(readme, expected);
+    }
+
+    #[test]
+    fn keeps_the_readme_without_new_data() {
+        let original = [
+            "# Advent of Code",
+            "",
+            MARKER,
+            "- [x] Day1",
+            "  - [x] Part 1: 25.6µs @ 10000 samples",
+            "  - [x] Part 2: 23.1µs @ 10000 samples",
+            "- [ ] Day2",
+            "  - [x] Part 1: 4.9µs @ 10000 samples",
+            "  - Brute force for now.",
+            "- [ ] Day3",
+            MARKER,
+            "",
+        ]
+        .join("\n");
+        let statuses: Vec<DayStatus> = (1..=3)
+            .map(|day| DayStatus {
+                tests: None,
+                ..status(day, [false, false], None)
+            })
+            .collect();
+
+        let mut readme = original.clone();
+        update_content(&mut readme, &statuses).unwrap();
+        assert_eq!(readme, original);
+    }
+
+    #[test]
+    fn errors_without_markers() {
+        let mut readme = "# readme".to_string();
+        assert!(update_content(&mut readme, &[]).is_err());
+    }
+}
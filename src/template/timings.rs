@@ -1,24 +1,61 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::bench::parse_duration;
+use crate::template::{BenchStats, Day, MemoryStats, PuzzleId, Step, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
+/// Represents benchmark times for a single puzzle.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub puzzle: PuzzleId,
+    /// Parsing shared by both parts, for solutions with a `parse` step.
+    pub parse: Option<BenchStats>,
+    pub part_1: Option<BenchStats>,
+    pub part_2: Option<BenchStats>,
+    /// Both parts solved at once, for solutions with a `solve` step.
+    pub combined: Option<BenchStats>,
+    /// Heap usage of each step, measured by `cargo time --memory`.
+    pub memory: Vec<(Step, MemoryStats)>,
+    /// Steps that were stopped by the timeout they exceeded.
+    pub timeouts: Vec<(Step, Duration)>,
+    /// Sum of the median durations of parsing and the parts.
     pub total_nanos: f64,
 }
 
-/// Represents benchmark times for a set of days.
+impl Timing {
+    /// Returns the heap usage of a step, if it was measured.
+    pub fn memory(&self, step: Step) -> Option<&MemoryStats> {
+        self.memory.iter().find(|(s, _)| *s == step).map(|(_, m)| m)
+    }
+
+    /// Returns the timeout a step exceeded, if it timed out.
+    pub fn timeout(&self, step: Step) -> Option<Duration> {
+        self.timeouts
+            .iter()
+            .find(|(s, _)| *s == step)
+            .map(|(_, t)| *t)
+    }
+}
+
+/// A stored timing along with the commit and time it was recorded at.
+#[derive(Clone, Debug)]
+pub struct TimingRun {
+    pub timing: Timing,
+    /// Short hash of the `HEAD` commit, suffixed with `-dirty` for uncommitted changes.
+    pub commit: Option<String>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Represents benchmark times for a set of days: the latest timing of every day
+/// and the history of every stored run.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
     pub data: Vec<Timing>,
+    pub history: Vec<TimingRun>,
 }
 
 impl Timings {
@@ -42,17 +79,63 @@ impl Timings {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            // keep the heap usage of the last memory run.
+            if timing.memory.is_empty()
+                && let Some(stored) = self.get(timing.puzzle)
+            {
+                timing.memory.clone_from(&stored.memory);
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.puzzle);
+
+        let mut history = self.history.clone();
+        history.extend(new.history.iter().cloned());
+
+        Timings { data, history }
+    }
+
+    /// Merge a new run into the stored timings and append it to their history.
+    pub fn record(&self, new: &Self, commit: Option<&str>, timestamp: u64) -> Self {
+        let mut merged = self.merge(new);
+
+        merged
+            .history
+            .extend(new.data.iter().map(|timing| TimingRun {
+                timing: timing.clone(),
+                commit: commit.map(Into::into),
+                timestamp,
+            }));
+
+        merged
+    }
+
+    /// Returns the latest timing of a puzzle.
+    pub fn get(&self, puzzle: PuzzleId) -> Option<&Timing> {
+        self.data.iter().find(|t| t.puzzle == puzzle)
+    }
+
+    /// Merge the heap usage of a memory run into `self`, keeping the stored durations.
+    pub fn merge_memory(&self, new: &Self) -> Self {
+        let mut merged = self.clone();
+
+        for timing in &new.data {
+            match merged.data.iter_mut().find(|t| t.puzzle == timing.puzzle) {
+                Some(stored) => stored.memory.clone_from(&timing.memory),
+                None => merged.data.push(timing.clone()),
+            }
+        }
+
+        merged.data.sort_unstable_by_key(|a| a.puzzle);
+        merged
     }
 
     /// Sum up total duration of timings as millis.
@@ -60,10 +143,10 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
+        self.data.iter().any(|t| {
+            t.puzzle == puzzle && (t.combined.is_some() || t.part_1.is_some() && t.part_2.is_some())
+        })
     }
 }
 
@@ -77,6 +160,10 @@ impl From<Timings> for JsonValue {
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
 
         JsonValue::Object(map)
     }
@@ -88,19 +175,33 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // NOTE: files stored before the history was kept have no `history`.
+        let json_history = match json.get("history") {
+            None => &vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?,
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(TimingRun::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -111,11 +212,54 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::from(parse));
+        }
+
+        if let Some(combined) = &value.combined {
+            map.insert("combined".into(), JsonValue::from(combined));
+        }
+
+        if !value.timeouts.is_empty() {
+            map.insert(
+                "timeouts".into(),
+                JsonValue::Object(
+                    value
+                        .timeouts
+                        .iter()
+                        .map(|(step, timeout)| {
+                            (step_key(*step), JsonValue::Number(timeout.as_secs_f64()))
+                        })
+                        .collect(),
+                ),
+            );
+        }
+
+        if !value.memory.is_empty() {
+            map.insert(
+                "memory".into(),
+                JsonValue::Object(
+                    value
+                        .memory
+                        .iter()
+                        .map(|(step, memory)| (step_key(*step), JsonValue::from(memory)))
+                        .collect(),
+                ),
+            );
+        }
+
+        let part_1 = value.part_1.as_ref().map(JsonValue::from);
+        let part_2 = value.part_2.as_ref().map(JsonValue::from);
 
         map.insert(
             "part_1".into(),
@@ -145,21 +289,77 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
+        // NOTE: timings stored before multi-year support have no year, they belong to the configured one.
+        let year = match json.get("year") {
+            None => Year::current(),
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+        };
+
+        let puzzle = json
             .get("day")
             .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+            .and_then(|day| day.parse().ok())
+            .and_then(Day::new)
+            .and_then(|day| PuzzleId::try_new(year, day))
+            .ok_or("Expected timing.day to be a Day of timing.year.")?;
+
+        // NOTE: only solutions with a `parse` or `solve` step store their timing.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(v) => parse_part(v).ok_or("Expected timing.parse to be null or stats.")?,
+        };
+
+        let combined = match json.get("combined") {
+            None => None,
+            Some(v) => parse_part(v).ok_or("Expected timing.combined to be null or stats.")?,
+        };
 
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .and_then(parse_part)
+            .ok_or("Expected timing.part_1 to be null or stats.")?;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            .and_then(parse_part)
+            .ok_or("Expected timing.part_2 to be null or stats.")?;
+
+        let mut memory = match json.get("memory") {
+            None => vec![],
+            Some(v) => v
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected timing.memory to be an object.")?
+                .iter()
+                .map(|(key, v)| {
+                    let step = parse_step_key(key)
+                        .ok_or(format!("Expected timing.memory.{key} to be a step."))?;
+                    Ok((step, MemoryStats::try_from(v)?))
+                })
+                .collect::<Result<Vec<_>, String>>()?,
+        };
+        memory.sort_unstable_by_key(|(step, _)| step_key(*step));
+
+        let mut timeouts = match json.get("timeouts") {
+            None => vec![],
+            Some(v) => v
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected timing.timeouts to be an object.")?
+                .iter()
+                .map(|(key, v)| {
+                    let step = parse_step_key(key)
+                        .ok_or(format!("Expected timing.timeouts.{key} to be a step."))?;
+                    let timeout = v
+                        .get::<f64>()
+                        .and_then(|&secs| Duration::try_from_secs_f64(secs).ok())
+                        .ok_or(format!("Expected timing.timeouts.{key} to be seconds."))?;
+                    Ok((step, timeout))
+                })
+                .collect::<Result<Vec<_>, String>>()?,
+        };
+        timeouts.sort_unstable_by_key(|(step, _)| step_key(*step));
 
         let total_nanos = json
             .get("total_nanos")
@@ -167,49 +367,173 @@ impl TryFrom<&JsonValue> for Timing {
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
         Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            puzzle,
+            parse,
+            part_1,
+            part_2,
+            combined,
+            memory,
+            timeouts,
             total_nanos,
         })
     }
 }
 
+impl From<&TimingRun> for JsonValue {
+    fn from(value: &TimingRun) -> Self {
+        let mut json = JsonValue::from(&value.timing);
+
+        if let JsonValue::Object(map) = &mut json {
+            map.insert(
+                "commit".into(),
+                match &value.commit {
+                    Some(commit) => JsonValue::String(commit.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+            #[allow(clippy::cast_precision_loss)]
+            map.insert(
+                "timestamp".into(),
+                JsonValue::Number(value.timestamp as f64),
+            );
+        }
+
+        json
+    }
+}
+
+impl TryFrom<&JsonValue> for TimingRun {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let timing = Timing::try_from(value)?;
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected timing.commit to be null or string.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|&t| t as u64)
+            .ok_or("Expected timing.timestamp to be a number.")?;
+
+        Ok(TimingRun {
+            timing,
+            commit: commit.cloned(),
+            timestamp,
+        })
+    }
+}
+
+/// Key of a step in a stored timing, e.g. `part_1`.
+fn step_key(step: Step) -> String {
+    match step {
+        Step::Parse => "parse".into(),
+        Step::Part(part) => format!("part_{part}"),
+        Step::Both => "combined".into(),
+    }
+}
+
+fn parse_step_key(key: &str) -> Option<Step> {
+    match key {
+        "parse" => Some(Step::Parse),
+        "part_1" => Some(Step::Part(1)),
+        "part_2" => Some(Step::Part(2)),
+        "combined" => Some(Step::Both),
+        _ => None,
+    }
+}
+
+/// Parses the timing of a part, returns `None` if it is malformed.
+// NOTE: timings stored before statistics were kept only have a formatted mean, e.g. `"1.2ms"`.
+fn parse_part(value: &JsonValue) -> Option<Option<BenchStats>> {
+    if value.is_null() {
+        return Some(None);
+    }
+
+    match value.get::<String>() {
+        Some(legacy) => parse_duration(legacy).map(|d| Some(BenchStats::from_duration(d, 0))),
+        None => BenchStats::try_from(value).ok().map(Some),
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
-mod tests {
-    use crate::day;
+pub(super) mod tests {
+    use crate::puzzle;
+
+    use std::time::Duration;
 
     use super::{Timing, Timings};
+    use crate::template::{BenchStats, MemoryStats, Step};
+
+    pub(crate) fn stats(millis: u64) -> BenchStats {
+        BenchStats::from_duration(Duration::from_millis(millis), 100)
+    }
+
+    pub(crate) fn memory(peak_bytes: u64, total_allocations: u64, total_bytes: u64) -> MemoryStats {
+        MemoryStats {
+            peak_bytes,
+            total_allocations,
+            total_bytes,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
+                    part_1: Some(stats(10)),
+                    part_2: Some(stats(20)),
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    puzzle: puzzle!(2025, 2),
+                    parse: None,
+                    part_1: Some(stats(30)),
+                    part_2: Some(stats(40)),
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
+                    puzzle: puzzle!(2025, 4),
+                    parse: None,
+                    part_1: Some(stats(40)),
                     part_2: None,
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 4e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use tinyjson::JsonValue;
+
+        use super::{Step, get_mock_timings, memory, stats};
+        use crate::{
+            day, puzzle,
+            template::{BenchStats, PuzzleId, Year, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -217,12 +541,62 @@ mod tests {
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.puzzle, PuzzleId::new(Year::current(), day!(1)));
+            assert_eq!(
+                timing.part_1,
+                Some(BenchStats::from_duration(Duration::from_millis(1), 0))
+            );
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_year() {
+            let json = r#"{ "data": [{ "year": "2024", "day": "05", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.first().unwrap().puzzle, puzzle!(2024, 5));
+        }
+
+        #[test]
+        fn round_trips_statistics() {
+            let mut timings = get_mock_timings();
+            timings.data[0].parse = Some(stats(5));
+            timings.data[2].combined = Some(stats(45));
+            timings.data[1].memory = vec![
+                (Step::Part(1), memory(64, 1, 64)),
+                (Step::Part(2), memory(32, 1, 32)),
+            ];
+            timings.data[1].timeouts = vec![(Step::Part(2), Duration::from_secs(5))];
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 3);
+            assert_eq!(parsed.data[0].parse, Some(stats(5)));
+            assert_eq!(parsed.data[1].parse, None);
+            assert_eq!(parsed.data[1].part_1, Some(stats(30)));
+            assert_eq!(parsed.data[2].part_2, None);
+            assert_eq!(parsed.data[2].combined, Some(stats(45)));
+            assert_eq!(
+                parsed.data[1].memory(Step::Part(2)),
+                Some(&memory(32, 1, 32))
+            );
+            assert!(parsed.data[0].memory.is_empty());
+            assert_eq!(
+                parsed.data[1].timeout(Step::Part(2)),
+                Some(Duration::from_secs(5))
+            );
+        }
+
+        #[test]
+        fn round_trips_history() {
+            let timings = Timings::default().record(&get_mock_timings(), Some("abc1234"), 100);
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.history.len(), 3);
+            assert_eq!(parsed.history[2].timing.part_2, None);
+            assert_eq!(parsed.history[2].commit.as_deref(), Some("abc1234"));
+            assert_eq!(parsed.history[2].timestamp, 100);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -269,8 +643,9 @@ mod tests {
     }
 
     mod is_day_complete {
+        use super::stats;
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
         };
 
@@ -278,70 +653,132 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
+                    part_1: Some(stats(1)),
+                    part_2: Some(stats(2)),
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 3_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
+                    part_1: Some(stats(1)),
                     part_2: None,
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 1_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 0.0,
                 }],
+                history: vec![],
+            };
+
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), false);
+        }
+
+        #[test]
+        fn handles_combined_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    combined: Some(stats(3)),
+                    memory: vec![],
+                    timeouts: vec![],
+                    total_nanos: 3_000_000_000_f64,
+                }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), true);
         }
     }
 
     mod merge {
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
         };
 
-        use super::get_mock_timings;
+        use super::{Step, get_mock_timings, memory, stats};
+
+        #[test]
+        fn merges_memory() {
+            let mut memory_run = get_mock_timings();
+            memory_run.data.truncate(1);
+            memory_run.data[0].part_1 = None;
+            memory_run.data[0].memory = vec![(Step::Part(1), memory(64, 1, 64))];
+
+            let merged = get_mock_timings().merge_memory(&memory_run);
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.data[0].part_1, Some(stats(10)));
+            assert_eq!(
+                merged.data[0].memory(Step::Part(1)),
+                Some(&memory(64, 1, 64))
+            );
+
+            // a timed run keeps the stored heap usage.
+            let merged = merged.merge(&get_mock_timings());
+            assert_eq!(
+                merged.data[0].memory(Step::Part(1)),
+                Some(&memory(64, 1, 64))
+            );
+        }
 
         #[test]
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: puzzle!(2025, 3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2025, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2025, 2));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2025, 3));
+            assert_eq!(merged.data[3].puzzle, puzzle!(2025, 4));
         }
 
         #[test]
@@ -350,19 +787,24 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2025, 2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: None,
+                    memory: vec![],
+                    timeouts: vec![],
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2025, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2025, 2));
             assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2025, 4));
         }
 
         #[test]
@@ -380,5 +822,18 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn records_history() {
+            let timings = Timings::default().record(&get_mock_timings(), Some("abc1234"), 100);
+            let timings = timings.record(&get_mock_timings(), None, 200);
+
+            assert_eq!(timings.data.len(), 3);
+            assert_eq!(timings.history.len(), 6);
+            assert_eq!(timings.history[0].commit.as_deref(), Some("abc1234"));
+            assert_eq!(timings.history[0].timestamp, 100);
+            assert_eq!(timings.history[5].commit, None);
+            assert_eq!(timings.history[5].timestamp, 200);
+        }
     }
 }
@@ -0,0 +1,208 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::Day;
+
+/// The first year Advent of Code took place.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// The year used when `AOC_YEAR` is not set.
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// The first year with a shortened calendar of [`SHORT_CALENDAR_DAYS`] puzzles.
+pub const SHORT_CALENDAR_SINCE: u16 = 2025;
+
+/// Number of puzzles in the classic calendar.
+pub const FULL_CALENDAR_DAYS: u8 = 25;
+
+/// Number of puzzles in the shortened calendar.
+pub const SHORT_CALENDAR_DAYS: u8 = 12;
+
+/// A valid year of Advent of Code (i.e. an integer not below 2015).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a valid event year,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Returns the year configured through the `AOC_YEAR` environment variable,
+    /// falling back to [`DEFAULT_YEAR`] if it is missing or invalid.
+    pub fn current() -> Self {
+        Self::__from_env(env::var("AOC_YEAR").ok().as_deref())
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    // Not part of the public API. Parses the value of `AOC_YEAR` in a const context so that
+    // `solution!` can resolve the year at compile time.
+    #[doc(hidden)]
+    pub const fn __from_env(value: Option<&str>) -> Self {
+        let fallback = Self(DEFAULT_YEAR);
+
+        let Some(value) = value else {
+            return fallback;
+        };
+
+        let bytes = value.as_bytes();
+        if bytes.is_empty() || bytes.len() > 4 {
+            return fallback;
+        }
+
+        let mut year: u16 = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                return fallback;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        if year < FIRST_YEAR {
+            fallback
+        } else {
+            Self(year)
+        }
+    }
+
+    /// Returns how many puzzles are released in this year: 25 up to 2024 and 12 from 2025 on.
+    ///
+    /// This can be overridden per year with `AOC_DAYS_<year>`, e.g. `AOC_DAYS_2025 = "12"`.
+    pub fn day_count(self) -> u8 {
+        env::var(format!("AOC_DAYS_{}", self.0))
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .filter(|x| (1..=FULL_CALENDAR_DAYS).contains(x))
+            .unwrap_or(if self.0 < SHORT_CALENDAR_SINCE {
+                FULL_CALENDAR_DAYS
+            } else {
+                SHORT_CALENDAR_DAYS
+            })
+    }
+
+    /// Returns `true` if the [`Day`] is part of this year's calendar.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.day_count()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl PartialOrd<u16> for Year {
+    fn partial_cmp(&self, other: &u16) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year starting from {FIRST_YEAR}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value starting from 2015"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DEFAULT_YEAR, Year};
+
+    #[test]
+    fn parses_years_from_env() {
+        assert_eq!(Year::__from_env(Some("2024")), Year(2024));
+        assert_eq!(Year::__from_env(None), Year(DEFAULT_YEAR));
+        assert_eq!(Year::__from_env(Some("")), Year(DEFAULT_YEAR));
+        assert_eq!(Year::__from_env(Some("20x4")), Year(DEFAULT_YEAR));
+        assert_eq!(Year::__from_env(Some("1999")), Year(DEFAULT_YEAR));
+    }
+
+    #[test]
+    fn counts_days_per_year() {
+        assert_eq!(Year(2015).day_count(), 25);
+        assert_eq!(Year(2024).day_count(), 25);
+        assert_eq!(Year(2025).day_count(), 12);
+        assert!(Year(2025).has_day(crate::day!(12)));
+        assert!(!Year(2025).has_day(crate::day!(13)));
+    }
+
+    #[test]
+    fn parses_years_from_str() {
+        assert_eq!("2015".parse::<Year>().unwrap(), Year(2015));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("foo".parse::<Year>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */